@@ -8,21 +8,185 @@
 //!
 //! [procfs-url]: https://github.com/torvalds/linux/blob/master/Documentation/filesystems/proc.txt
 
-#[macro_use]
-extern crate enum_primitive;
 extern crate hex;
-extern crate num;
 
 use hex::FromHex;
-use num::FromPrimitive;
 
-use std::convert::Infallible;
+use std::collections::HashMap;
 use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fs;
 use std::fs::File;
 use std::io;
-use std::io::Read;
-use std::net::Ipv4Addr;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Clock ticks per second for times exported through procfs.
+const USER_HZ: u64 = 100;
+
+/// Clock ticks per second, as reported by `sysconf(_SC_CLK_TCK)`.
+///
+/// Without the `sysconf` feature (or if the call fails) this is `USER_HZ`,
+/// 100, which is what every mainstream architecture uses.
+#[cfg(feature = "sysconf")]
+pub fn clk_tck() -> u64 {
+    // SAFETY: sysconf has no preconditions and only reads configuration.
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if ticks > 0 {
+        ticks as u64
+    } else {
+        USER_HZ
+    }
+}
+
+/// Clock ticks per second, as reported by `sysconf(_SC_CLK_TCK)`.
+///
+/// Without the `sysconf` feature (or if the call fails) this is `USER_HZ`,
+/// 100, which is what every mainstream architecture uses.
+#[cfg(not(feature = "sysconf"))]
+pub fn clk_tck() -> u64 {
+    USER_HZ
+}
+
+/// Size in bytes of a memory page.
+///
+/// This is 4096 on x86, x86_64 and most arm64 configurations; it is not read
+/// from the running system.
+pub const PAGE_SIZE: u64 = 4096;
+
+/// Error produced when a procfs line cannot be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The line ended before the named field was found.
+    MissingField { field: &'static str, line: String },
+    /// The named field was present but could not be decoded.
+    InvalidField { field: &'static str, line: String },
+}
+
+impl ParseError {
+    fn missing(field: &'static str, line: &str) -> ParseError {
+        ParseError::MissingField {
+            field,
+            line: line.to_owned(),
+        }
+    }
+
+    fn invalid(field: &'static str, line: &str) -> ParseError {
+        ParseError::InvalidField {
+            field,
+            line: line.to_owned(),
+        }
+    }
+
+    /// Name of the field that failed to parse.
+    pub fn field(&self) -> &'static str {
+        match *self {
+            ParseError::MissingField { field, .. } | ParseError::InvalidField { field, .. } => {
+                field
+            }
+        }
+    }
+
+    /// The offending line, as read from procfs.
+    pub fn line(&self) -> &str {
+        match *self {
+            ParseError::MissingField { ref line, .. }
+            | ParseError::InvalidField { ref line, .. } => line,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::MissingField { field, ref line } => {
+                write!(f, "missing field `{}` in line: {}", field, line)
+            }
+            ParseError::InvalidField { field, ref line } => {
+                write!(f, "invalid field `{}` in line: {}", field, line)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(err: ParseError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Change in a monotonic counter from `previous` to `current`.
+///
+/// A counter that went backwards (wrapped, or reset by a reboot) yields 0
+/// rather than a bogus spike. Every `*_since` and `*_delta` method uses this.
+pub fn delta(current: u64, previous: u64) -> u64 {
+    current.saturating_sub(previous)
+}
+
+/// Controls how `from_str_with` parsers treat content they cannot decode.
+///
+/// The `FromStr` implementations, and so the readers, use the default, which
+/// is lenient. Lenient parsing is best-effort: an unparseable numeric token
+/// reads as 0 rather than being reported, so positional columns keep their
+/// meaning.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions {
+    /// Return `Err` on any unparseable numeric token.
+    pub strict: bool,
+    /// Collect the per-core `cpuN` lines of `/proc/stat` into `Stat::cpus`.
+    /// Skipping them saves an allocation per core on large machines.
+    pub per_cpu: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions::lenient()
+    }
+}
+
+impl ParseOptions {
+    /// Fail on any unparseable numeric token.
+    pub fn strict() -> ParseOptions {
+        ParseOptions {
+            strict: true,
+            per_cpu: true,
+        }
+    }
+
+    /// Read unparseable numeric tokens as 0. This is the default.
+    pub fn lenient() -> ParseOptions {
+        ParseOptions {
+            strict: false,
+            per_cpu: true,
+        }
+    }
+
+    /// Leave `Stat::cpus` empty, keeping only the aggregate `cpu` line.
+    pub fn aggregate_only(self) -> ParseOptions {
+        ParseOptions {
+            per_cpu: false,
+            ..self
+        }
+    }
+
+    /// Passes `result` through in strict mode; swallows errors as `None`
+    /// otherwise.
+    fn check<T>(self, result: Result<T, ParseError>) -> Result<Option<T>, ParseError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if self.strict => Err(err),
+            Err(_) => Ok(None),
+        }
+    }
+}
 
 /// Represents the output of `cat /proc/stat`
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -31,81 +195,390 @@ pub struct Stat {
     pub cpus: Vec<Vec<u64>>,
     pub intr: Vec<u64>,
     pub ctxt: u64,
-    pub btime: u32,
+    /// Boot time, in seconds since the Unix epoch.
+    pub btime: u64,
     pub processes: u32,
     pub procs_running: u32,
     pub procs_blocked: u32,
     pub softirq: Vec<u64>,
+    /// Pages paged in and out, from the `page` line of older kernels.
+    pub page: Option<(u64, u64)>,
+    /// Pages swapped in and out, from the `swap` line of older kernels.
+    pub swap: Option<(u64, u64)>,
 }
 
 impl FromStr for Stat {
-    type Err = Infallible;
+    type Err = ParseError;
+
+    /// Parses leniently; see [`Stat::from_str_with`] for a strict mode.
+    fn from_str(s: &str) -> Result<Stat, ParseError> {
+        Stat::from_str_with(s, ParseOptions::default())
+    }
+}
 
-    fn from_str(s: &str) -> Result<Stat, Infallible> {
+impl Stat {
+    /// Parses `/proc/stat` content, failing on or tolerating unparseable
+    /// numeric tokens depending on `opts`.
+    ///
+    /// In lenient mode a bad token inside a list line (`cpu`, `intr`, ...)
+    /// reads as 0, keeping later columns in place, and a bad scalar (`ctxt`,
+    /// `btime`, ...) keeps its default.
+    pub fn from_str_with(s: &str, opts: ParseOptions) -> Result<Stat, ParseError> {
         let mut stat: Stat = Default::default();
         for (line_num, line) in s.lines().enumerate() {
             if line_num == 0 {
-                stat.cpu = to_vecu64(line);
+                stat.cpu = to_vecu64_with(line, opts)?;
             }
 
-            if line.starts_with("cpu") && line_num > 0 {
-                stat.cpus.push(to_vecu64(line));
+            if line.starts_with("cpu") && line_num > 0 && opts.per_cpu {
+                stat.cpus.push(to_vecu64_with(line, opts)?);
             }
 
             if line.starts_with("intr") {
-                stat.intr = to_vecu64(line);
+                stat.intr = to_vecu64_with(line, opts)?;
             }
 
             if line.starts_with("ctxt") {
-                let mut chunks = line.split_whitespace();
-                chunks.next();
-
-                stat.ctxt = chunks.next().unwrap().parse::<u64>().unwrap();
+                stat.ctxt = opts.check(to_scalar(line, "ctxt"))?.unwrap_or_default();
             }
 
             if line.starts_with("btime") {
-                let mut chunks = line.split_whitespace();
-                chunks.next();
-
-                stat.btime = chunks.next().unwrap().parse::<u32>().unwrap();
+                stat.btime = opts.check(to_scalar(line, "btime"))?.unwrap_or_default();
             }
 
             if line.starts_with("processes") {
-                let mut chunks = line.split_whitespace();
-                chunks.next();
-
-                stat.processes = chunks.next().unwrap().parse::<u32>().unwrap();
+                stat.processes = opts
+                    .check(to_scalar(line, "processes"))?
+                    .unwrap_or_default();
             }
 
             if line.starts_with("procs_running") {
-                let mut chunks = line.split_whitespace();
-                chunks.next();
-
-                stat.procs_running = chunks.next().unwrap().parse::<u32>().unwrap();
+                stat.procs_running = opts
+                    .check(to_scalar(line, "procs_running"))?
+                    .unwrap_or_default();
             }
 
             if line.starts_with("procs_blocked") {
-                let mut chunks = line.split_whitespace();
-                chunks.next();
-
-                stat.procs_blocked = chunks.next().unwrap().parse::<u32>().unwrap();
+                stat.procs_blocked = opts
+                    .check(to_scalar(line, "procs_blocked"))?
+                    .unwrap_or_default();
             }
 
             if line.starts_with("softirq") {
-                stat.softirq = to_vecu64(line);
+                stat.softirq = to_vecu64_with(line, opts)?;
+            }
+
+            if line.starts_with("page ") {
+                stat.page = opts.check(to_in_out(line))?;
+            }
+
+            if line.starts_with("swap ") {
+                stat.swap = opts.check(to_in_out(line))?;
             }
         }
 
         Ok(stat)
     }
+
+    /// Parses `/proc/stat` content from any reader, e.g. a saved copy.
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Stat> {
+        Ok(read_to_string(reader)?.parse()?)
+    }
+
+    /// Aggregate CPU time across all cores, decoded from `cpu`.
+    pub fn cpu_time(&self) -> CpuTime {
+        CpuTime::from(&self.cpu[..])
+    }
+
+    /// Column `idx` of the aggregate `cpu` line, or `None` if the kernel
+    /// printed fewer columns.
+    pub fn cpu_field(&self, idx: usize) -> Option<u64> {
+        self.cpu.get(idx).copied()
+    }
+
+    /// Aggregate user time, in jiffies.
+    pub fn user(&self) -> Option<u64> {
+        self.cpu_field(0)
+    }
+
+    /// Aggregate idle time, in jiffies.
+    pub fn idle(&self) -> Option<u64> {
+        self.cpu_field(3)
+    }
+
+    /// Aggregate time waiting on I/O, in jiffies. Absent before Linux 2.5.41.
+    pub fn iowait(&self) -> Option<u64> {
+        self.cpu_field(4)
+    }
+
+    /// Number of logical CPUs, i.e. `cpuN` lines.
+    pub fn num_cpus(&self) -> usize {
+        self.cpus.len()
+    }
+
+    /// Whether per-core `cpuN` lines were present, not just the aggregate.
+    pub fn has_per_cpu(&self) -> bool {
+        !self.cpus.is_empty()
+    }
+
+    /// Per-core CPU times, decoded from each `cpuN` line.
+    pub fn cpu_times(&self) -> Vec<CpuTime> {
+        self.cpus
+            .iter()
+            .map(|cpu| CpuTime::from(&cpu[..]))
+            .collect()
+    }
+
+    /// Processes forked since `previous`, or 0 if the counter went backwards.
+    pub fn forks_since(&self, previous: &Stat) -> u64 {
+        delta(u64::from(self.processes), u64::from(previous.processes))
+    }
+
+    /// Context switches since `previous`, or 0 if the counter went backwards.
+    pub fn ctxt_switches_since(&self, previous: &Stat) -> u64 {
+        delta(self.ctxt, previous.ctxt)
+    }
+
+    /// Boot time decoded from `btime`.
+    pub fn boot_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.btime)
+    }
+
+    /// Raw jiffies of each core (the `cpuN` lines, not the aggregate `cpu`)
+    /// paired with its index.
+    pub fn per_cpu(&self) -> impl Iterator<Item = (usize, &Vec<u64>)> {
+        self.cpus.iter().enumerate()
+    }
+
+    /// Decoded times of each core paired with its index.
+    pub fn per_cpu_time(&self) -> impl Iterator<Item = (usize, CpuTime)> + '_ {
+        self.per_cpu().map(|(i, cpu)| (i, CpuTime::from(&cpu[..])))
+    }
+
+    /// Fraction of time (0.0 - 1.0) all CPUs were busy between `previous`
+    /// and this snapshot.
+    pub fn cpu_usage_since(&self, previous: &Stat) -> f64 {
+        self.cpu_time().usage_since(&previous.cpu_time())
+    }
+
+    /// Per-core busy fraction between `previous` and this snapshot.
+    ///
+    /// The result has one entry per core in `self`. Cores that are missing
+    /// from `previous` (e.g. brought online in between) report 0.0.
+    pub fn cpus_usage_since(&self, previous: &Stat) -> Vec<f64> {
+        let previous = previous.cpu_times();
+
+        self.cpu_times()
+            .iter()
+            .enumerate()
+            .map(|(i, cpu)| match previous.get(i) {
+                Some(prev) => cpu.usage_since(prev),
+                None => 0.0,
+            })
+            .collect()
+    }
+
+    /// Interrupts serviced since boot, across all IRQs (first `intr` column).
+    pub fn total_interrupts(&self) -> u64 {
+        self.intr.first().copied().unwrap_or(0)
+    }
+
+    /// Per-IRQ interrupt counts, indexed by IRQ number, excluding the total.
+    pub fn interrupt_counts(&self) -> &[u64] {
+        self.intr.get(1..).unwrap_or(&[])
+    }
+
+    /// Per-type softirq counts, decoded from the `softirq` line.
+    pub fn softirq_breakdown(&self) -> SoftIrq {
+        SoftIrq::from(self.softirq.get(1..).unwrap_or(&[]))
+    }
+
+    /// Per-type softirqs raised since `previous`. A count lower than in
+    /// `previous` (e.g. after a counter wrap) yields 0 for that type.
+    pub fn softirq_delta(&self, previous: &Stat) -> SoftIrq {
+        let current = self.softirq_breakdown();
+        let previous = previous.softirq_breakdown();
+
+        SoftIrq {
+            hi: delta(current.hi, previous.hi),
+            timer: delta(current.timer, previous.timer),
+            net_tx: delta(current.net_tx, previous.net_tx),
+            net_rx: delta(current.net_rx, previous.net_rx),
+            block: delta(current.block, previous.block),
+            irq_poll: delta(current.irq_poll, previous.irq_poll),
+            tasklet: delta(current.tasklet, previous.tasklet),
+            sched: delta(current.sched, previous.sched),
+            hrtimer: delta(current.hrtimer, previous.hrtimer),
+            rcu: delta(current.rcu, previous.rcu),
+        }
+    }
+
+    /// Softirqs of all types raised since `previous`, from the leading total
+    /// of the `softirq` line.
+    pub fn total_softirq_delta(&self, previous: &Stat) -> u64 {
+        let total = |stat: &Stat| stat.softirq.first().copied().unwrap_or(0);
+        delta(total(self), total(previous))
+    }
+}
+
+/// Time spent by a CPU in each state, in jiffies (`USER_HZ`).
+///
+/// Older kernels omit the trailing columns; any missing field is zero.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct CpuTime {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+    pub guest: u64,
+    pub guest_nice: u64,
+}
+
+impl CpuTime {
+    /// Total jiffies spent in any state: the sum of every field except
+    /// `guest` and `guest_nice`.
+    ///
+    /// Those two are deliberately left out because the kernel already counts
+    /// guest time in `user` and `nice`; adding them would count it twice.
+    pub fn total(&self) -> u64 {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.iowait
+            + self.irq
+            + self.softirq
+            + self.steal
+    }
+
+    /// Jiffies spent idle, including time waiting on I/O.
+    pub fn idle_time(&self) -> u64 {
+        self.idle + self.iowait
+    }
+
+    /// Converts a tick count, e.g. `total()` or one of the fields, to a
+    /// `Duration` using `clk_tck()`.
+    ///
+    /// Saturates at `Duration::MAX` for counts too large to convert.
+    pub fn as_duration(ticks: u64) -> Duration {
+        clock_ticks_to_duration(ticks, clk_tck()).unwrap_or(Duration::MAX)
+    }
+
+    /// Fraction of time (0.0 - 1.0) spent busy between `previous` and `self`.
+    ///
+    /// Returns 0.0 if no time has elapsed between the two samples.
+    pub fn usage_since(&self, previous: &CpuTime) -> f64 {
+        let total = delta(self.total(), previous.total());
+        let idle = delta(self.idle_time(), previous.idle_time());
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        total.saturating_sub(idle) as f64 / total as f64
+    }
+}
+
+impl From<&[u64]> for CpuTime {
+    fn from(jiffies: &[u64]) -> CpuTime {
+        let field = |i: usize| jiffies.get(i).copied().unwrap_or(0);
+
+        CpuTime {
+            user: field(0),
+            nice: field(1),
+            system: field(2),
+            idle: field(3),
+            iowait: field(4),
+            irq: field(5),
+            softirq: field(6),
+            steal: field(7),
+            guest: field(8),
+            guest_nice: field(9),
+        }
+    }
+}
+
+impl FromStr for CpuTime {
+    type Err = ParseError;
+
+    /// Parses a single `cpu` or `cpuN` line from `/proc/stat`.
+    fn from_str(s: &str) -> Result<CpuTime, ParseError> {
+        let mut chunks = s.split_whitespace();
+        match chunks.next() {
+            Some(label) if label.starts_with("cpu") => {}
+            _ => return Err(ParseError::invalid("cpu", s)),
+        }
+
+        let jiffies = chunks
+            .map(|chunk| chunk.parse::<u64>())
+            .collect::<Result<Vec<u64>, _>>()
+            .map_err(|_| ParseError::invalid("cpu", s))?;
+
+        Ok(CpuTime::from(&jiffies[..]))
+    }
+}
+
+/// Number of softirqs serviced since boot, by type.
+///
+/// Kernels before 2.6.32 do not report `IRQ_POLL` (then `BLOCK_IOPOLL`), and
+/// older ones lack some trailing types; any missing count is zero.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SoftIrq {
+    pub hi: u64,
+    pub timer: u64,
+    pub net_tx: u64,
+    pub net_rx: u64,
+    pub block: u64,
+    pub irq_poll: u64,
+    pub tasklet: u64,
+    pub sched: u64,
+    pub hrtimer: u64,
+    pub rcu: u64,
+}
+
+impl From<&[u64]> for SoftIrq {
+    /// Maps per-type counts by position; the leading total must already be
+    /// stripped.
+    fn from(counts: &[u64]) -> SoftIrq {
+        let field = |i: usize| counts.get(i).copied().unwrap_or(0);
+        // Without the IRQ_POLL column, everything after BLOCK shifts left.
+        let (irq_poll, shift) = if counts.len() >= 10 {
+            (field(5), 0)
+        } else {
+            (0, 1)
+        };
+
+        SoftIrq {
+            hi: field(0),
+            timer: field(1),
+            net_tx: field(2),
+            net_rx: field(3),
+            block: field(4),
+            irq_poll,
+            tasklet: field(6 - shift),
+            sched: field(7 - shift),
+            hrtimer: field(8 - shift),
+            rcu: field(9 - shift),
+        }
+    }
 }
 
 /// Represents the output of `cat /proc/meminfo`
+///
+/// Sizes are in kB (1024 bytes) as reported by the kernel, except for the
+/// `huge_pages_*` fields, which are page counts. Use the `*_bytes()`
+/// accessors for values in bytes.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct MemInfo {
     pub mem_total: u64,
     pub mem_free: u64,
     pub mem_available: u64,
+    pub buffers: u64,
+    #[deprecated(note = "misspelled; use `buffers`")]
     pub bufers: u64,
     pub cached: u64,
     pub swap_cached: u64,
@@ -148,263 +621,3686 @@ pub struct MemInfo {
     pub hugepagesize: u64,
     pub direct_map_4k: u64,
     pub direct_map_2m: u64,
+    /// Any line whose key has no dedicated field, keyed without the colon.
+    pub extra: HashMap<String, u64>,
 }
 
-impl FromStr for MemInfo {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<MemInfo, Infallible> {
-        let mut meminfo: MemInfo = Default::default();
+/// Unit of a `MemInfo` field.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MemUnit {
+    Kilobytes,
+    Count,
+}
 
-        for line in s.lines() {
-            if line.starts_with("MemTotal") {
-                meminfo.mem_total = to_u64(line);
+macro_rules! kb_to_bytes {
+    ($($field:ident => $method:ident),* $(,)*) => {
+        $(
+            #[doc = concat!("`", stringify!($field), "` in bytes.")]
+            pub fn $method(&self) -> u64 {
+                self.$field * 1024
             }
+        )*
 
-            if line.starts_with("MemFree") {
-                meminfo.mem_free = to_u64(line);
-            }
+        const KB_FIELDS: &[&str] = &[$(stringify!($field)),*];
+    };
+}
 
-            if line.starts_with("MemAvailable") {
-                meminfo.mem_available = to_u64(line);
-            }
+impl MemInfo {
+    /// Parses `/proc/meminfo` content from any reader, e.g. a saved copy.
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<MemInfo> {
+        Ok(read_to_string(reader)?.parse()?)
+    }
 
-            if line.starts_with("Buffers") {
-                meminfo.bufers = to_u64(line);
-            }
+    kb_to_bytes! {
+        mem_total => mem_total_bytes,
+        mem_free => mem_free_bytes,
+        mem_available => mem_available_bytes,
+        buffers => buffers_bytes,
+        cached => cached_bytes,
+        swap_cached => swap_cached_bytes,
+        active => active_bytes,
+        inactive => inactive_bytes,
+        active_anon => active_anon_bytes,
+        inactive_anon => inactive_anon_bytes,
+        active_file => active_file_bytes,
+        inactive_file => inactive_file_bytes,
+        unevictable => unevictable_bytes,
+        mlocked => mlocked_bytes,
+        swap_total => swap_total_bytes,
+        swap_free => swap_free_bytes,
+        dirty => dirty_bytes,
+        writeback => writeback_bytes,
+        anon_pages => anon_pages_bytes,
+        mapped => mapped_bytes,
+        shmem => shmem_bytes,
+        slab => slab_bytes,
+        s_reclaimable => s_reclaimable_bytes,
+        s_unreclaim => s_unreclaim_bytes,
+        kernel_stack => kernel_stack_bytes,
+        page_tables => page_tables_bytes,
+        nfs_unstable => nfs_unstable_bytes,
+        bounce => bounce_bytes,
+        writeback_tmp => writeback_tmp_bytes,
+        commit_limit => commit_limit_bytes,
+        committed_as => committed_as_bytes,
+        vmalloc_total => vmalloc_total_bytes,
+        vmalloc_used => vmalloc_used_bytes,
+        vmalloc_chunk => vmalloc_chunk_bytes,
+        hardware_corrupted => hardware_corrupted_bytes,
+        anon_huge_pages => anon_huge_pages_bytes,
+        cma_total => cma_total_bytes,
+        cma_free => cma_free_bytes,
+        hugepagesize => hugepagesize_bytes,
+        direct_map_4k => direct_map_4k_bytes,
+        direct_map_2m => direct_map_2m_bytes,
+    }
 
-            if line.starts_with("Cached") {
-                meminfo.cached = to_u64(line);
-            }
+    /// Name and value of every dedicated field, in declaration order. The
+    /// deprecated `bufers` alias and `extra` are not included.
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        [
+            ("mem_total", self.mem_total),
+            ("mem_free", self.mem_free),
+            ("mem_available", self.mem_available),
+            ("buffers", self.buffers),
+            ("cached", self.cached),
+            ("swap_cached", self.swap_cached),
+            ("active", self.active),
+            ("inactive", self.inactive),
+            ("active_anon", self.active_anon),
+            ("inactive_anon", self.inactive_anon),
+            ("active_file", self.active_file),
+            ("inactive_file", self.inactive_file),
+            ("unevictable", self.unevictable),
+            ("mlocked", self.mlocked),
+            ("swap_total", self.swap_total),
+            ("swap_free", self.swap_free),
+            ("dirty", self.dirty),
+            ("writeback", self.writeback),
+            ("anon_pages", self.anon_pages),
+            ("mapped", self.mapped),
+            ("shmem", self.shmem),
+            ("slab", self.slab),
+            ("s_reclaimable", self.s_reclaimable),
+            ("s_unreclaim", self.s_unreclaim),
+            ("kernel_stack", self.kernel_stack),
+            ("page_tables", self.page_tables),
+            ("nfs_unstable", self.nfs_unstable),
+            ("bounce", self.bounce),
+            ("writeback_tmp", self.writeback_tmp),
+            ("commit_limit", self.commit_limit),
+            ("committed_as", self.committed_as),
+            ("vmalloc_total", self.vmalloc_total),
+            ("vmalloc_used", self.vmalloc_used),
+            ("vmalloc_chunk", self.vmalloc_chunk),
+            ("hardware_corrupted", self.hardware_corrupted),
+            ("anon_huge_pages", self.anon_huge_pages),
+            ("cma_total", self.cma_total),
+            ("cma_free", self.cma_free),
+            ("huge_pages_total", self.huge_pages_total),
+            ("huge_pages_free", self.huge_pages_free),
+            ("huge_pages_rsvd", self.huge_pages_rsvd),
+            ("huge_pages_surp", self.huge_pages_surp),
+            ("hugepagesize", self.hugepagesize),
+            ("direct_map_4k", self.direct_map_4k),
+            ("direct_map_2m", self.direct_map_2m),
+        ]
+        .into_iter()
+    }
 
-            if line.starts_with("SwapCached") {
-                meminfo.swap_cached = to_u64(line);
+    /// Unit of the field called `name`, e.g. `Kilobytes` for `mem_total`
+    /// and `Count` for `huge_pages_total`. Unknown names give `None`.
+    pub fn field_unit(name: &str) -> Option<MemUnit> {
+        match name {
+            "huge_pages_total" | "huge_pages_free" | "huge_pages_rsvd" | "huge_pages_surp" => {
+                Some(MemUnit::Count)
             }
+            "bufers" => Some(MemUnit::Kilobytes),
+            _ if MemInfo::KB_FIELDS.contains(&name) => Some(MemUnit::Kilobytes),
+            _ => None,
+        }
+    }
 
-            if line.starts_with("Active") {
-                meminfo.active = to_u64(line);
-            }
+    #[deprecated(note = "misspelled; use `buffers_bytes`")]
+    pub fn bufers_bytes(&self) -> u64 {
+        self.buffers_bytes()
+    }
 
-            if line.starts_with("Inactive") {
-                meminfo.inactive = to_u64(line);
-            }
+    /// Memory in use, in kB, as reported by the "used" column of `free`:
+    ///
+    /// `MemTotal - MemFree - Buffers - Cached - SReclaimable`
+    pub fn used(&self) -> u64 {
+        self.mem_total
+            .saturating_sub(self.mem_free)
+            .saturating_sub(self.buffers)
+            .saturating_sub(self.cached)
+            .saturating_sub(self.s_reclaimable)
+    }
 
-            if line.starts_with("Active(anon)") {
-                meminfo.active_anon = to_u64(line);
-            }
+    /// `used()` as a percentage of `MemTotal`, or 0.0 if the total is unknown.
+    pub fn used_percent(&self) -> f64 {
+        if self.mem_total == 0 {
+            return 0.0;
+        }
 
-            if line.starts_with("Inactive(anon)") {
-                meminfo.inactive_anon = to_u64(line);
-            }
+        self.used() as f64 / self.mem_total as f64 * 100.0
+    }
 
-            if line.starts_with("Active(file)") {
-                meminfo.active_file = to_u64(line);
-            }
+    /// Memory available for new allocations without swapping, in kB.
+    ///
+    /// Returns `MemAvailable` when present. Kernels before 3.14 lack it, in
+    /// which case it is estimated the way the kernel computes it: free memory
+    /// above the low watermark, plus the page cache and reclaimable slab,
+    /// each less whichever is smaller of half of it and the low watermark.
+    /// The watermark itself is approximated from `MemTotal` using the
+    /// kernel's default `min_free_kbytes` formula (`sqrt(16 * total)`,
+    /// clamped to 128 kB - 256 MiB) plus 25%.
+    pub fn available_estimate(&self) -> u64 {
+        if self.mem_available != 0 {
+            return self.mem_available;
+        }
 
-            if line.starts_with("Inactive(file)") {
-                meminfo.inactive_file = to_u64(line);
-            }
+        let min_free = ((16.0 * self.mem_total as f64).sqrt() as u64).clamp(128, 262_144);
+        let low = min_free + min_free / 4;
 
-            if line.starts_with("Unevictable") {
-                meminfo.unevictable = to_u64(line);
-            }
+        self.mem_free.saturating_sub(low)
+            + (self.cached - (self.cached / 2).min(low))
+            + (self.s_reclaimable - (self.s_reclaimable / 2).min(low))
+    }
 
-            if line.starts_with("Mlocked") {
-                meminfo.mlocked = to_u64(line);
-            }
+    /// Swap in use, in kB: `SwapTotal - SwapFree`.
+    pub fn swap_used(&self) -> u64 {
+        self.swap_total.saturating_sub(self.swap_free)
+    }
 
-            if line.starts_with("SwapTotal") {
-                meminfo.swap_total = to_u64(line);
-            }
+    /// `swap_used()` as a percentage of `SwapTotal`, or 0.0 without swap.
+    pub fn swap_used_percent(&self) -> f64 {
+        if self.swap_total == 0 {
+            return 0.0;
+        }
 
-            if line.starts_with("SwapFree") {
-                meminfo.swap_free = to_u64(line);
-            }
+        self.swap_used() as f64 / self.swap_total as f64 * 100.0
+    }
+}
 
-            if line.starts_with("Dirty") {
-                meminfo.dirty = to_u64(line);
-            }
+impl FromStr for MemInfo {
+    type Err = ParseError;
 
-            if line.starts_with("Writeback") {
-                meminfo.writeback = to_u64(line);
-            }
+    /// Parses leniently; see [`MemInfo::from_str_with`] for a strict mode.
+    fn from_str(s: &str) -> Result<MemInfo, ParseError> {
+        MemInfo::from_str_with(s, ParseOptions::default())
+    }
+}
 
-            if line.starts_with("AnonPages") {
-                meminfo.anon_pages = to_u64(line);
-            }
+impl MemInfo {
+    /// Parses `/proc/meminfo` content, failing or skipping lines with an
+    /// unparseable value depending on `opts`.
+    pub fn from_str_with(s: &str, opts: ParseOptions) -> Result<MemInfo, ParseError> {
+        let mut meminfo: MemInfo = Default::default();
 
-            if line.starts_with("Mapped") {
-                meminfo.mapped = to_u64(line);
-            }
+        for line in s.lines() {
+            // Each line is split once and its key dispatched through a single
+            // exact match, as several keys share a common prefix (e.g.
+            // `Active`, `Active(anon)` and `Active(file)`).
+            let (key, rest) = match line.split_once(':') {
+                Some(pair) => pair,
+                None => continue,
+            };
+            let value = match opts.check(to_field(rest.split_whitespace().next(), "value", line))? {
+                Some(value) => value,
+                None => continue,
+            };
 
-            if line.starts_with("Shmem") {
-                meminfo.shmem = to_u64(line);
+            match key {
+                "MemTotal" => meminfo.mem_total = value,
+                "MemFree" => meminfo.mem_free = value,
+                "MemAvailable" => meminfo.mem_available = value,
+                "Buffers" => {
+                    meminfo.buffers = value;
+                    #[allow(deprecated)]
+                    {
+                        meminfo.bufers = meminfo.buffers;
+                    }
+                }
+                "Cached" => meminfo.cached = value,
+                "SwapCached" => meminfo.swap_cached = value,
+                "Active" => meminfo.active = value,
+                "Inactive" => meminfo.inactive = value,
+                "Active(anon)" => meminfo.active_anon = value,
+                "Inactive(anon)" => meminfo.inactive_anon = value,
+                "Active(file)" => meminfo.active_file = value,
+                "Inactive(file)" => meminfo.inactive_file = value,
+                "Unevictable" => meminfo.unevictable = value,
+                "Mlocked" => meminfo.mlocked = value,
+                "SwapTotal" => meminfo.swap_total = value,
+                "SwapFree" => meminfo.swap_free = value,
+                "Dirty" => meminfo.dirty = value,
+                "Writeback" => meminfo.writeback = value,
+                "AnonPages" => meminfo.anon_pages = value,
+                "Mapped" => meminfo.mapped = value,
+                "Shmem" => meminfo.shmem = value,
+                "Slab" => meminfo.slab = value,
+                "SReclaimable" => meminfo.s_reclaimable = value,
+                "SUnreclaim" => meminfo.s_unreclaim = value,
+                "KernelStack" => meminfo.kernel_stack = value,
+                "PageTables" => meminfo.page_tables = value,
+                "NFS_Unstable" => meminfo.nfs_unstable = value,
+                "Bounce" => meminfo.bounce = value,
+                "WritebackTmp" => meminfo.writeback_tmp = value,
+                "CommitLimit" => meminfo.commit_limit = value,
+                "Committed_AS" => meminfo.committed_as = value,
+                "VmallocTotal" => meminfo.vmalloc_total = value,
+                "VmallocUsed" => meminfo.vmalloc_used = value,
+                "VmallocChunk" => meminfo.vmalloc_chunk = value,
+                "HardwareCorrupted" => meminfo.hardware_corrupted = value,
+                "AnonHugePages" => meminfo.anon_huge_pages = value,
+                "CmaTotal" => meminfo.cma_total = value,
+                "CmaFree" => meminfo.cma_free = value,
+                "HugePages_Total" => meminfo.huge_pages_total = value,
+                "HugePages_Free" => meminfo.huge_pages_free = value,
+                "HugePages_Rsvd" => meminfo.huge_pages_rsvd = value,
+                "HugePages_Surp" => meminfo.huge_pages_surp = value,
+                "Hugepagesize" => meminfo.hugepagesize = value,
+                "DirectMap4k" => meminfo.direct_map_4k = value,
+                "DirectMap2M" => meminfo.direct_map_2m = value,
+                _ => {
+                    meminfo.extra.insert(key.to_owned(), value);
+                }
             }
+        }
 
-            if line.starts_with("Slab") {
-                meminfo.slab = to_u64(line);
-            }
+        Ok(meminfo)
+    }
+}
 
-            if line.starts_with("SReclaimable") {
-                meminfo.s_reclaimable = to_u64(line);
-            }
+/// Represents the output of `cat /proc/loadavg`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct LoadAvg {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+    pub runnable: u32,
+    pub total: u32,
+    pub last_pid: u32,
+}
 
-            if line.starts_with("SUnreclaim") {
-                meminfo.s_unreclaim = to_u64(line);
-            }
+impl FromStr for LoadAvg {
+    type Err = ParseError;
 
-            if line.starts_with("KernelStack") {
-                meminfo.kernel_stack = to_u64(line);
-            }
+    fn from_str(s: &str) -> Result<LoadAvg, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let one = to_field(chunks.next(), "one", s)?;
+        let five = to_field(chunks.next(), "five", s)?;
+        let fifteen = to_field(chunks.next(), "fifteen", s)?;
 
-            if line.starts_with("PageTables") {
-                meminfo.page_tables = to_u64(line);
-            }
+        // Scheduling entities are reported as a <runnable>/<total> pair.
+        let mut procs = chunks
+            .next()
+            .ok_or_else(|| ParseError::missing("runnable/total", s))?
+            .split('/');
+        let runnable = to_field(procs.next(), "runnable", s)?;
+        let total = to_field(procs.next(), "total", s)?;
 
-            if line.starts_with("NFS_Unstable") {
-                meminfo.nfs_unstable = to_u64(line);
-            }
+        let last_pid = to_field(chunks.next(), "last_pid", s)?;
 
-            if line.starts_with("Bounce") {
-                meminfo.bounce = to_u64(line);
-            }
+        Ok(LoadAvg {
+            one,
+            five,
+            fifteen,
+            runnable,
+            total,
+            last_pid,
+        })
+    }
+}
 
-            if line.starts_with("WritebackTmp") {
-                meminfo.writeback_tmp = to_u64(line);
-            }
+impl LoadAvg {
+    /// Stat of the most recently created process, `last_pid`, read from
+    /// `/proc`. See `ProcfsReader::newest_process()`.
+    pub fn newest_process(&self) -> io::Result<Option<ProcessStat>> {
+        ProcfsReader::default().newest_process(self)
+    }
+}
 
-            if line.starts_with("CommitLimit") {
-                meminfo.commit_limit = to_u64(line);
-            }
+/// Represents the output of `cat /proc/uptime`, in seconds
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Uptime {
+    pub uptime: f64,
+    pub idle: f64,
+}
 
-            if line.starts_with("Committed_AS") {
-                meminfo.committed_as = to_u64(line);
-            }
+impl Uptime {
+    /// Time since boot.
+    pub fn uptime_duration(&self) -> Duration {
+        Duration::from_secs_f64(self.uptime)
+    }
 
-            if line.starts_with("VmallocTotal") {
-                meminfo.vmalloc_total = to_u64(line);
-            }
+    /// Time spent idle, summed across all CPUs.
+    pub fn idle_duration(&self) -> Duration {
+        Duration::from_secs_f64(self.idle)
+    }
+}
 
-            if line.starts_with("VmallocUsed") {
-                meminfo.vmalloc_used = to_u64(line);
-            }
+impl FromStr for Uptime {
+    type Err = ParseError;
 
-            if line.starts_with("VmallocChunk") {
-                meminfo.vmalloc_chunk = to_u64(line);
-            }
+    fn from_str(s: &str) -> Result<Uptime, ParseError> {
+        let mut chunks = s.split_whitespace();
 
-            if line.starts_with("HardwareCorrupted") {
-                meminfo.hardware_corrupted = to_u64(line);
-            }
+        Ok(Uptime {
+            uptime: to_field(chunks.next(), "uptime", s)?,
+            idle: to_field(chunks.next(), "idle", s)?,
+        })
+    }
+}
 
-            if line.starts_with("AnonHugePages") {
-                meminfo.anon_huge_pages = to_u64(line);
-            }
+/// Represents the output of `cat /proc/vmstat`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct VmStat {
+    pub values: HashMap<String, u64>,
+}
 
-            if line.starts_with("CmaTotal") {
-                meminfo.cma_total = to_u64(line);
-            }
+impl VmStat {
+    /// Value of an arbitrary counter, by its `/proc/vmstat` key.
+    pub fn get(&self, key: &str) -> Option<u64> {
+        self.values.get(key).copied()
+    }
 
-            if line.starts_with("CmaFree") {
-                meminfo.cma_free = to_u64(line);
-            }
+    /// Page faults, minor and major.
+    pub fn pgfault(&self) -> Option<u64> {
+        self.get("pgfault")
+    }
 
-            if line.starts_with("HugePages_Total") {
-                meminfo.huge_pages_total = to_u64(line);
-            }
+    /// Page faults that required disk I/O.
+    pub fn pgmajfault(&self) -> Option<u64> {
+        self.get("pgmajfault")
+    }
 
-            if line.starts_with("HugePages_Free") {
-                meminfo.huge_pages_free = to_u64(line);
-            }
+    /// Pages swapped in.
+    pub fn pswpin(&self) -> Option<u64> {
+        self.get("pswpin")
+    }
 
-            if line.starts_with("HugePages_Rsvd") {
-                meminfo.huge_pages_rsvd = to_u64(line);
-            }
+    /// Pages swapped out.
+    pub fn pswpout(&self) -> Option<u64> {
+        self.get("pswpout")
+    }
 
-            if line.starts_with("HugePages_Surp") {
-                meminfo.huge_pages_surp = to_u64(line);
-            }
+    /// Pages currently free.
+    pub fn nr_free_pages(&self) -> Option<u64> {
+        self.get("nr_free_pages")
+    }
+}
 
-            if line.starts_with("Hugepagesize") {
-                meminfo.hugepagesize = to_u64(line);
-            }
+impl FromStr for VmStat {
+    type Err = ParseError;
 
-            if line.starts_with("DirectMap4k") {
-                meminfo.direct_map_4k = to_u64(line);
-            }
+    fn from_str(s: &str) -> Result<VmStat, ParseError> {
+        let mut vmstat: VmStat = Default::default();
 
-            if line.starts_with("DirectMap2M") {
-                meminfo.direct_map_2m = to_u64(line);
-            }
+        for line in s.lines() {
+            let mut chunks = line.split_whitespace();
+            let key = match chunks.next() {
+                Some(key) => key,
+                None => continue,
+            };
+
+            let value = to_field(chunks.next(), "value", line)?;
+            vmstat.values.insert(key.to_owned(), value);
         }
 
-        Ok(meminfo)
+        Ok(vmstat)
     }
 }
 
-enum_from_primitive! {
-    /// Represents TCP socket's state.
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub enum SocketState {
-        Established = 1,
-        SynSent = 2,
-        SynRecv = 3,
-        FinWait1 = 4,
-        FinWait2 = 5,
-        TimeWait = 6,
-        Close = 7,
-        CloseWait = 8,
-        LastAck = 9,
-        Listen = 10,
-        Closing = 11
+/// Represents a line (block device) in output of `cat /proc/diskstats`
+///
+/// Times are in milliseconds. The discard columns appear from Linux 4.18 and
+/// the flush columns from 5.5; they are `None` on older kernels.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DiskStat {
+    pub major: u32,
+    pub minor: u32,
+    pub name: String,
+    pub reads_completed: u64,
+    pub reads_merged: u64,
+    pub sectors_read: u64,
+    pub time_reading: u64,
+    pub writes_completed: u64,
+    pub writes_merged: u64,
+    pub sectors_written: u64,
+    pub time_writing: u64,
+    pub ios_in_progress: u64,
+    pub time_in_io: u64,
+    pub weighted_time_in_io: u64,
+    pub discards_completed: Option<u64>,
+    pub discards_merged: Option<u64>,
+    pub sectors_discarded: Option<u64>,
+    pub time_discarding: Option<u64>,
+    pub flushes_completed: Option<u64>,
+    pub time_flushing: Option<u64>,
+}
+
+impl FromStr for DiskStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<DiskStat, ParseError> {
+        let mut chunks = s.split_whitespace();
+
+        Ok(DiskStat {
+            major: to_field(chunks.next(), "major", s)?,
+            minor: to_field(chunks.next(), "minor", s)?,
+            name: to_field(chunks.next(), "name", s)?,
+            reads_completed: to_field(chunks.next(), "reads_completed", s)?,
+            reads_merged: to_field(chunks.next(), "reads_merged", s)?,
+            sectors_read: to_field(chunks.next(), "sectors_read", s)?,
+            time_reading: to_field(chunks.next(), "time_reading", s)?,
+            writes_completed: to_field(chunks.next(), "writes_completed", s)?,
+            writes_merged: to_field(chunks.next(), "writes_merged", s)?,
+            sectors_written: to_field(chunks.next(), "sectors_written", s)?,
+            time_writing: to_field(chunks.next(), "time_writing", s)?,
+            ios_in_progress: to_field(chunks.next(), "ios_in_progress", s)?,
+            time_in_io: to_field(chunks.next(), "time_in_io", s)?,
+            weighted_time_in_io: to_field(chunks.next(), "weighted_time_in_io", s)?,
+            discards_completed: to_opt_field(chunks.next(), "discards_completed", s)?,
+            discards_merged: to_opt_field(chunks.next(), "discards_merged", s)?,
+            sectors_discarded: to_opt_field(chunks.next(), "sectors_discarded", s)?,
+            time_discarding: to_opt_field(chunks.next(), "time_discarding", s)?,
+            flushes_completed: to_opt_field(chunks.next(), "flushes_completed", s)?,
+            time_flushing: to_opt_field(chunks.next(), "time_flushing", s)?,
+        })
     }
 }
 
-/// Represents TCP socket's timer status.
-#[derive(Clone, Debug, PartialEq)]
-pub enum SocketTimerState {
-    // TODO: other timer states, timeout
-    Inactive,
-    Active(u64),
+/// Represents a line (interface) in output of `cat /proc/net/dev`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetDev {
+    pub interface: String,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errs: u64,
+    pub rx_drop: u64,
+    pub rx_fifo: u64,
+    pub rx_frame: u64,
+    pub rx_compressed: u64,
+    pub rx_multicast: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errs: u64,
+    pub tx_drop: u64,
+    pub tx_fifo: u64,
+    pub tx_colls: u64,
+    pub tx_carrier: u64,
+    pub tx_compressed: u64,
 }
 
-/// Represents a line (socket) in output of `cat /proc/net/{tcp,udp}`
-#[derive(Clone)]
-pub struct Socket {
-    pub sl: u64,
-    pub local_address: Ipv4Addr,
-    pub local_port: u16,
-    pub remote_address: Ipv4Addr,
-    pub remote_port: u16,
-    pub state: SocketState,
-    pub tx_queue: u64,
-    pub rx_queue: u64,
-    pub timer: SocketTimerState,
-    pub uid: u32,
+impl FromStr for NetDev {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<NetDev, ParseError> {
+        // The name is terminated by a colon which, on older kernels, is not
+        // followed by a space once the byte counter gets wide enough.
+        let colon = s
+            .find(':')
+            .ok_or_else(|| ParseError::missing("interface", s))?;
+        let mut chunks = s[colon + 1..].split_whitespace();
+
+        Ok(NetDev {
+            interface: s[..colon].trim().to_owned(),
+            rx_bytes: to_field(chunks.next(), "rx_bytes", s)?,
+            rx_packets: to_field(chunks.next(), "rx_packets", s)?,
+            rx_errs: to_field(chunks.next(), "rx_errs", s)?,
+            rx_drop: to_field(chunks.next(), "rx_drop", s)?,
+            rx_fifo: to_field(chunks.next(), "rx_fifo", s)?,
+            rx_frame: to_field(chunks.next(), "rx_frame", s)?,
+            rx_compressed: to_field(chunks.next(), "rx_compressed", s)?,
+            rx_multicast: to_field(chunks.next(), "rx_multicast", s)?,
+            tx_bytes: to_field(chunks.next(), "tx_bytes", s)?,
+            tx_packets: to_field(chunks.next(), "tx_packets", s)?,
+            tx_errs: to_field(chunks.next(), "tx_errs", s)?,
+            tx_drop: to_field(chunks.next(), "tx_drop", s)?,
+            tx_fifo: to_field(chunks.next(), "tx_fifo", s)?,
+            tx_colls: to_field(chunks.next(), "tx_colls", s)?,
+            tx_carrier: to_field(chunks.next(), "tx_carrier", s)?,
+            tx_compressed: to_field(chunks.next(), "tx_compressed", s)?,
+        })
+    }
+}
+
+impl NetDev {
+    /// Per-second traffic between `previous`, a sample of the same interface,
+    /// and `self`, taken `elapsed` apart.
+    ///
+    /// A counter that went backwards counts as 0, and so does every rate if
+    /// `elapsed` is zero. Use `net_dev_rates()` to pair samples by name.
+    pub fn rate_since(&self, previous: &NetDev, elapsed: Duration) -> NetDevRate {
+        let secs = elapsed.as_secs_f64();
+        let rate = |current, previous| {
+            if secs == 0.0 {
+                return 0.0;
+            }
+
+            delta(current, previous) as f64 / secs
+        };
+
+        NetDevRate {
+            interface: self.interface.clone(),
+            rx_bytes: rate(self.rx_bytes, previous.rx_bytes),
+            rx_packets: rate(self.rx_packets, previous.rx_packets),
+            tx_bytes: rate(self.tx_bytes, previous.tx_bytes),
+            tx_packets: rate(self.tx_packets, previous.tx_packets),
+        }
+    }
+}
+
+/// Per-second traffic of an interface, from `NetDev::rate_since()`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct NetDevRate {
+    pub interface: String,
+    pub rx_bytes: f64,
+    pub rx_packets: f64,
+    pub tx_bytes: f64,
+    pub tx_packets: f64,
+}
+
+/// Rates of every interface in `current` that also appears in `previous`,
+/// matched by name.
+pub fn net_dev_rates(
+    current: &[NetDev],
+    previous: &[NetDev],
+    elapsed: Duration,
+) -> Vec<NetDevRate> {
+    current
+        .iter()
+        .filter_map(|dev| {
+            let prev = previous
+                .iter()
+                .find(|prev| prev.interface == dev.interface)?;
+            Some(dev.rate_since(prev, elapsed))
+        })
+        .collect()
+}
+
+/// Represents the output of `cat /proc/[pid]/stat`, up to `rss`
+///
+/// CPU times are in clock ticks, `vsize` is in bytes and `rss` is in pages.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ProcessStat {
+    pub pid: u32,
+    pub comm: String,
+    pub state: char,
+    pub ppid: u32,
+    pub pgrp: u32,
+    pub session: u32,
+    pub tty_nr: i32,
+    pub tpgid: i32,
+    pub flags: u32,
+    pub minflt: u64,
+    pub cminflt: u64,
+    pub majflt: u64,
+    pub cmajflt: u64,
+    pub utime: u64,
+    pub stime: u64,
+    pub cutime: i64,
+    pub cstime: i64,
+    pub priority: i64,
+    pub nice: i64,
+    pub num_threads: u64,
+    pub starttime: u64,
+    pub vsize: u64,
+    pub rss: u64,
+}
+
+impl FromStr for ProcessStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<ProcessStat, ParseError> {
+        // `comm` is wrapped in parentheses and may itself contain spaces or
+        // parentheses, so it spans from the first `(` to the last `)`.
+        let open = s.find('(').ok_or_else(|| ParseError::missing("comm", s))?;
+        let close = s.rfind(')').ok_or_else(|| ParseError::missing("comm", s))?;
+        if close < open {
+            return Err(ParseError::invalid("comm", s));
+        }
+
+        let pid = to_field(Some(s[..open].trim()), "pid", s)?;
+        let comm = s[open + 1..close].to_owned();
+        let mut chunks = s[close + 1..].split_whitespace();
+        let state = to_field(chunks.next(), "state", s)?;
+
+        Ok(ProcessStat {
+            pid,
+            comm,
+            state,
+            ppid: to_field(chunks.next(), "ppid", s)?,
+            pgrp: to_field(chunks.next(), "pgrp", s)?,
+            session: to_field(chunks.next(), "session", s)?,
+            tty_nr: to_field(chunks.next(), "tty_nr", s)?,
+            tpgid: to_field(chunks.next(), "tpgid", s)?,
+            flags: to_field(chunks.next(), "flags", s)?,
+            minflt: to_field(chunks.next(), "minflt", s)?,
+            cminflt: to_field(chunks.next(), "cminflt", s)?,
+            majflt: to_field(chunks.next(), "majflt", s)?,
+            cmajflt: to_field(chunks.next(), "cmajflt", s)?,
+            utime: to_field(chunks.next(), "utime", s)?,
+            stime: to_field(chunks.next(), "stime", s)?,
+            cutime: to_field(chunks.next(), "cutime", s)?,
+            cstime: to_field(chunks.next(), "cstime", s)?,
+            priority: to_field(chunks.next(), "priority", s)?,
+            nice: to_field(chunks.next(), "nice", s)?,
+            num_threads: to_field(chunks.next(), "num_threads", s)?,
+            // itrealvalue - always 0 since 2.6.17
+            starttime: to_field(chunks.nth(1), "starttime", s)?,
+            vsize: to_field(chunks.next(), "vsize", s)?,
+            rss: to_field(chunks.next(), "rss", s)?,
+        })
+    }
+}
+
+impl ProcessStat {
+    /// CPU time spent in user and kernel mode, in seconds, scaled by
+    /// `clk_tck()`.
+    pub fn cpu_time_secs(&self) -> f64 {
+        (self.utime + self.stime) as f64 / clk_tck() as f64
+    }
+}
+
+/// Represents the output of `cat /proc/[pid]/statm`, in pages
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ProcessMem {
+    pub size: u64,
+    pub resident: u64,
+    pub shared: u64,
+    pub text: u64,
+    /// Unused since Linux 2.6, always 0.
+    pub lib: u64,
+    pub data: u64,
+    /// Unused since Linux 2.6, always 0.
+    pub dt: u64,
+}
+
+impl ProcessMem {
+    /// Resident set size in bytes, assuming `PAGE_SIZE`.
+    pub fn resident_bytes(&self) -> u64 {
+        self.resident * PAGE_SIZE
+    }
+}
+
+impl FromStr for ProcessMem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<ProcessMem, ParseError> {
+        let mut chunks = s.split_whitespace();
+
+        Ok(ProcessMem {
+            size: to_field(chunks.next(), "size", s)?,
+            resident: to_field(chunks.next(), "resident", s)?,
+            shared: to_field(chunks.next(), "shared", s)?,
+            text: to_field(chunks.next(), "text", s)?,
+            lib: to_field(chunks.next(), "lib", s)?,
+            data: to_field(chunks.next(), "data", s)?,
+            dt: to_field(chunks.next(), "dt", s)?,
+        })
+    }
+}
+
+/// Represents the output of `cat /proc/[pid]/io`
+///
+/// `rchar`/`wchar` count every byte passed to read/write calls, while
+/// `read_bytes`/`write_bytes` count what actually hit the storage layer.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ProcessIo {
+    pub rchar: u64,
+    pub wchar: u64,
+    pub syscr: u64,
+    pub syscw: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub cancelled_write_bytes: u64,
+}
+
+impl FromStr for ProcessIo {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<ProcessIo, ParseError> {
+        let mut io: ProcessIo = Default::default();
+
+        for line in s.lines() {
+            let (key, rest) = match line.split_once(':') {
+                Some(pair) => pair,
+                None => continue,
+            };
+            let value = to_field(rest.split_whitespace().next(), "value", line)?;
+
+            match key {
+                "rchar" => io.rchar = value,
+                "wchar" => io.wchar = value,
+                "syscr" => io.syscr = value,
+                "syscw" => io.syscw = value,
+                "read_bytes" => io.read_bytes = value,
+                "write_bytes" => io.write_bytes = value,
+                "cancelled_write_bytes" => io.cancelled_write_bytes = value,
+                _ => {}
+            }
+        }
+
+        Ok(io)
+    }
+}
+
+/// `/proc/[pid]/io` counters, as returned by `pid_io()`.
+pub type PidIo = ProcessIo;
+
+/// Represents the output of `cat /proc/[pid]/status`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ProcessStatus {
+    /// Raw values keyed by field name, with surrounding whitespace trimmed.
+    pub values: HashMap<String, String>,
+}
+
+impl ProcessStatus {
+    /// Raw value of an arbitrary field.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.get("Name")
+    }
+
+    /// Process state, e.g. `S (sleeping)`.
+    pub fn state(&self) -> Option<&str> {
+        self.get("State")
+    }
+
+    pub fn vm_peak_kb(&self) -> Option<u64> {
+        self.kb("VmPeak")
+    }
+
+    pub fn vm_size_kb(&self) -> Option<u64> {
+        self.kb("VmSize")
+    }
+
+    pub fn vm_hwm_kb(&self) -> Option<u64> {
+        self.kb("VmHWM")
+    }
+
+    pub fn vm_rss_kb(&self) -> Option<u64> {
+        self.kb("VmRSS")
+    }
+
+    pub fn vm_swap_kb(&self) -> Option<u64> {
+        self.kb("VmSwap")
+    }
+
+    pub fn threads(&self) -> Option<u64> {
+        self.count("Threads")
+    }
+
+    pub fn voluntary_ctxt_switches(&self) -> Option<u64> {
+        self.count("voluntary_ctxt_switches")
+    }
+
+    pub fn nonvoluntary_ctxt_switches(&self) -> Option<u64> {
+        self.count("nonvoluntary_ctxt_switches")
+    }
+
+    fn kb(&self, key: &str) -> Option<u64> {
+        self.get(key)?.trim_end_matches("kB").trim().parse().ok()
+    }
+
+    fn count(&self, key: &str) -> Option<u64> {
+        self.get(key)?.parse().ok()
+    }
+}
+
+impl FromStr for ProcessStatus {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<ProcessStatus, ParseError> {
+        let mut status: ProcessStatus = Default::default();
+
+        for line in s.lines() {
+            let colon = match line.find(':') {
+                Some(colon) => colon,
+                None => continue,
+            };
+
+            status.values.insert(
+                line[..colon].to_owned(),
+                line[colon + 1..].trim().to_owned(),
+            );
+        }
+
+        Ok(status)
+    }
+}
+
+/// Identifies a line in `/proc/interrupts`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Irq {
+    /// A numbered hardware IRQ line.
+    Number(u32),
+    /// An architecture-specific source such as `NMI` or `LOC`.
+    Named(String),
+}
+
+/// Represents a line (IRQ) in output of `cat /proc/interrupts`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Interrupt {
+    pub irq: Irq,
+    /// Interrupts serviced per CPU. Summary lines such as `ERR` and `MIS`
+    /// carry a single system-wide count instead.
+    pub counts: Vec<u64>,
+    /// Controller, trigger and device name(s), whitespace-normalized.
+    pub description: String,
+}
+
+fn to_interrupts(s: &str) -> Result<Vec<Interrupt>, ParseError> {
+    let mut lines = s.lines();
+    let cpus = match lines.next() {
+        Some(header) => header.split_whitespace().count(),
+        None => return Ok(Vec::new()),
+    };
+
+    let mut interrupts = Vec::new();
+    for line in lines.filter(|line| !line.trim().is_empty()) {
+        let colon = line
+            .find(':')
+            .ok_or_else(|| ParseError::missing("irq", line))?;
+        let label = line[..colon].trim();
+        let irq = match label.parse::<u32>() {
+            Ok(number) => Irq::Number(number),
+            Err(_) => Irq::Named(label.to_owned()),
+        };
+
+        let mut chunks = line[colon + 1..].split_whitespace().peekable();
+        let mut counts = Vec::with_capacity(cpus);
+        while counts.len() < cpus {
+            match chunks.peek().and_then(|chunk| chunk.parse::<u64>().ok()) {
+                Some(count) => counts.push(count),
+                None => break,
+            }
+            chunks.next();
+        }
+
+        interrupts.push(Interrupt {
+            irq,
+            counts,
+            description: chunks.collect::<Vec<&str>>().join(" "),
+        });
+    }
+
+    Ok(interrupts)
+}
+
+/// Represents a block (logical CPU) in output of `cat /proc/cpuinfo`
+///
+/// Keys vary by architecture; any that are absent keep their default value.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CpuInfo {
+    pub processor: u32,
+    pub vendor_id: String,
+    pub model_name: String,
+    pub cpu_mhz: f64,
+    pub cache_size_kb: u64,
+    pub core_id: u32,
+    pub physical_id: u32,
+    pub flags: Vec<String>,
+}
+
+impl FromStr for CpuInfo {
+    type Err = ParseError;
+
+    /// Parses a single processor block.
+    fn from_str(s: &str) -> Result<CpuInfo, ParseError> {
+        let mut cpuinfo: CpuInfo = Default::default();
+
+        for line in s.lines() {
+            let colon = match line.find(':') {
+                Some(colon) => colon,
+                None => continue,
+            };
+            let value = line[colon + 1..].trim();
+
+            match line[..colon].trim() {
+                "processor" => cpuinfo.processor = to_field(Some(value), "processor", line)?,
+                "vendor_id" => cpuinfo.vendor_id = value.to_owned(),
+                "model name" => cpuinfo.model_name = value.to_owned(),
+                "cpu MHz" => cpuinfo.cpu_mhz = to_field(Some(value), "cpu MHz", line)?,
+                "cache size" => {
+                    let size = value.split_whitespace().next();
+                    cpuinfo.cache_size_kb = to_field(size, "cache size", line)?;
+                }
+                "core id" => cpuinfo.core_id = to_field(Some(value), "core id", line)?,
+                "physical id" => cpuinfo.physical_id = to_field(Some(value), "physical id", line)?,
+                "flags" => cpuinfo.flags = value.split_whitespace().map(str::to_owned).collect(),
+                _ => {}
+            }
+        }
+
+        Ok(cpuinfo)
+    }
+}
+
+fn to_cpuinfos(s: &str) -> Result<Vec<CpuInfo>, ParseError> {
+    // Each logical CPU is a block of lines terminated by a blank line.
+    s.split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Represents a block (algorithm) in output of `cat /proc/crypto`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Crypto {
+    pub name: String,
+    pub driver: String,
+    /// `kernel` for built-in algorithms.
+    pub module: String,
+    pub priority: i32,
+    /// e.g. `cipher`, `skcipher`, `shash` or `aead`.
+    pub type_: String,
+    /// Remaining keys, e.g. `blocksize` or `min keysize`, which vary by type.
+    pub fields: HashMap<String, String>,
+}
+
+impl FromStr for Crypto {
+    type Err = ParseError;
+
+    /// Parses a single algorithm block.
+    fn from_str(s: &str) -> Result<Crypto, ParseError> {
+        let mut crypto: Crypto = Default::default();
+
+        for line in s.lines() {
+            let (key, value) = match line.split_once(':') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+
+            match key {
+                "name" => crypto.name = value.to_owned(),
+                "driver" => crypto.driver = value.to_owned(),
+                "module" => crypto.module = value.to_owned(),
+                "priority" => crypto.priority = to_field(Some(value), "priority", line)?,
+                "type" => crypto.type_ = value.to_owned(),
+                _ => {
+                    crypto.fields.insert(key.to_owned(), value.to_owned());
+                }
+            }
+        }
+
+        Ok(crypto)
+    }
+}
+
+fn to_cryptos(s: &str) -> Result<Vec<Crypto>, ParseError> {
+    // Each algorithm is a block of lines terminated by a blank line.
+    s.split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Represents the output of `cat /proc/pressure/{cpu,memory,io}`
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Pressure {
+    /// Time in which at least one task was stalled on the resource.
+    pub some: PressureRecord,
+    /// Time in which all non-idle tasks were stalled at once. Absent from
+    /// `cpu` on kernels before 5.13.
+    pub full: Option<PressureRecord>,
+}
+
+impl Pressure {
+    /// Whether the pressure differs from `other`: any average by more than
+    /// `epsilon`, any `total`, or the presence of the `full` line.
+    pub fn changed_since(&self, other: &Pressure, epsilon: f64) -> bool {
+        match (&self.full, &other.full) {
+            (Some(full), Some(other_full)) if full.changed_since(other_full, epsilon) => true,
+            (Some(_), None) | (None, Some(_)) => true,
+            _ => self.some.changed_since(&other.some, epsilon),
+        }
+    }
+}
+
+/// A single `some` or `full` line of a pressure file.
+///
+/// Averages are the percentage of wall time stalled over the trailing 10, 60
+/// and 300 seconds; `total` is the cumulative stall time in microseconds.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PressureRecord {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total: u64,
+}
+
+impl PressureRecord {
+    /// Whether any average differs from `other` by more than `epsilon`, or
+    /// `total` differs at all.
+    pub fn changed_since(&self, other: &PressureRecord, epsilon: f64) -> bool {
+        (self.avg10 - other.avg10).abs() > epsilon
+            || (self.avg60 - other.avg60).abs() > epsilon
+            || (self.avg300 - other.avg300).abs() > epsilon
+            || self.total != other.total
+    }
+}
+
+impl FromStr for PressureRecord {
+    type Err = ParseError;
+
+    /// Parses the `avg10=... total=...` fields, after the `some`/`full` label.
+    fn from_str(s: &str) -> Result<PressureRecord, ParseError> {
+        Ok(PressureRecord {
+            avg10: to_field(to_keyed(s, "avg10"), "avg10", s)?,
+            avg60: to_field(to_keyed(s, "avg60"), "avg60", s)?,
+            avg300: to_field(to_keyed(s, "avg300"), "avg300", s)?,
+            total: to_field(to_keyed(s, "total"), "total", s)?,
+        })
+    }
+}
+
+impl FromStr for Pressure {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Pressure, ParseError> {
+        let mut some = None;
+        let mut full = None;
+
+        for line in s.lines() {
+            let mut chunks = line.splitn(2, ' ');
+            match (chunks.next(), chunks.next()) {
+                (Some("some"), Some(record)) => some = Some(record.parse()?),
+                (Some("full"), Some(record)) => full = Some(record.parse()?),
+                _ => {}
+            }
+        }
+
+        Ok(Pressure {
+            some: some.ok_or_else(|| ParseError::missing("some", s))?,
+            full,
+        })
+    }
+}
+
+/// Represents a line (swap area) in output of `cat /proc/swaps`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Swap {
+    /// Path of the device or file. The kernel octal-escapes whitespace and
+    /// backslashes (e.g. `\040` for a space); these are decoded.
+    pub filename: String,
+    /// `partition` or `file`.
+    pub kind: String,
+    pub size_kb: u64,
+    pub used_kb: u64,
+    pub priority: i32,
+}
+
+impl FromStr for Swap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Swap, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let filename = chunks
+            .next()
+            .ok_or_else(|| ParseError::missing("filename", s))?;
+
+        Ok(Swap {
+            filename: unescape_octal(filename),
+            kind: to_field(chunks.next(), "kind", s)?,
+            size_kb: to_field(chunks.next(), "size", s)?,
+            used_kb: to_field(chunks.next(), "used", s)?,
+            priority: to_field(chunks.next(), "priority", s)?,
+        })
+    }
+}
+
+/// Represents a line (mount) in output of `cat /proc/mounts`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Mount {
+    /// Source device, with octal escapes (e.g. `\040`) decoded.
+    pub device: String,
+    /// Mount point, with octal escapes (e.g. `\040`) decoded.
+    pub mount_point: String,
+    pub fs_type: String,
+    pub options: Vec<String>,
+    pub dump: u32,
+    pub pass: u32,
+}
+
+impl FromStr for Mount {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Mount, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let mut next = |field| chunks.next().ok_or_else(|| ParseError::missing(field, s));
+
+        Ok(Mount {
+            device: unescape_octal(next("device")?),
+            mount_point: unescape_octal(next("mount_point")?),
+            fs_type: next("fs_type")?.to_owned(),
+            options: next("options")?.split(',').map(str::to_owned).collect(),
+            dump: to_field(Some(next("dump")?), "dump", s)?,
+            pass: to_field(Some(next("pass")?), "pass", s)?,
+        })
+    }
+}
+
+/// Represents a line (route) in output of `cat /proc/net/route`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Route {
+    pub iface: String,
+    pub destination: Ipv4Addr,
+    pub gateway: Ipv4Addr,
+    /// `RTF_*` flags, e.g. `0x1` (up) and `0x2` (gateway). See
+    /// `flags_decoded()`.
+    pub flags: u16,
+    pub metric: u32,
+    pub mask: Ipv4Addr,
+    pub mtu: u32,
+    pub window: u32,
+}
+
+impl Route {
+    pub fn flags_decoded(&self) -> RouteFlags {
+        RouteFlags(self.flags)
+    }
+}
+
+/// `RTF_*` bits of a `Route`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct RouteFlags(pub u16);
+
+impl RouteFlags {
+    pub const UP: RouteFlags = RouteFlags(0x0001);
+    pub const GATEWAY: RouteFlags = RouteFlags(0x0002);
+    pub const HOST: RouteFlags = RouteFlags(0x0004);
+    pub const REINSTATE: RouteFlags = RouteFlags(0x0008);
+    pub const DYNAMIC: RouteFlags = RouteFlags(0x0010);
+    pub const MODIFIED: RouteFlags = RouteFlags(0x0020);
+    pub const REJECT: RouteFlags = RouteFlags(0x0200);
+
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Whether every bit of `other` is set.
+    pub fn contains(&self, other: RouteFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.contains(RouteFlags::UP)
+    }
+
+    /// Whether the destination is reached through a gateway.
+    pub fn is_gateway(&self) -> bool {
+        self.contains(RouteFlags::GATEWAY)
+    }
+
+    /// Whether the route is to a single host rather than a network.
+    pub fn is_host(&self) -> bool {
+        self.contains(RouteFlags::HOST)
+    }
+}
+
+impl FromStr for Route {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Route, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let mut next = |field| chunks.next().ok_or_else(|| ParseError::missing(field, s));
+        let addr = |hex, field| to_ipv4addr(hex).ok_or_else(|| ParseError::invalid(field, s));
+
+        let iface = next("Iface")?.to_owned();
+        let destination = addr(next("Destination")?, "Destination")?;
+        let gateway = addr(next("Gateway")?, "Gateway")?;
+        let flags =
+            u16::from_str_radix(next("Flags")?, 16).map_err(|_| ParseError::invalid("Flags", s))?;
+        // RefCnt and Use are unused by the kernel.
+        next("RefCnt")?;
+        next("Use")?;
+
+        Ok(Route {
+            iface,
+            destination,
+            gateway,
+            flags,
+            metric: to_field(Some(next("Metric")?), "Metric", s)?,
+            mask: addr(next("Mask")?, "Mask")?,
+            mtu: to_field(Some(next("MTU")?), "MTU", s)?,
+            window: to_field(Some(next("Window")?), "Window", s)?,
+        })
+    }
+}
+
+/// Represents a line (AF_PACKET socket) in output of `cat /proc/net/packet`
+///
+/// `sk` and `proto` are printed in hex; every other column is decimal.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PacketSocket {
+    /// Kernel address of the socket, kept as the raw hex string.
+    pub sk: String,
+    pub ref_count: u32,
+    /// Socket type, e.g. 3 (`SOCK_RAW`) or 2 (`SOCK_DGRAM`).
+    pub type_: u16,
+    /// Ethernet protocol in network byte order, e.g. `0x0003` (`ETH_P_ALL`).
+    pub proto: u16,
+    /// Bound interface index, 0 if unbound.
+    pub iface: u32,
+    /// 1 if the socket is running (bound to a protocol hook).
+    pub r: u32,
+    /// Bytes allocated in the receive queue.
+    pub rmem: u32,
+    /// Owning uid.
+    pub user: u32,
+    pub inode: u64,
+}
+
+impl FromStr for PacketSocket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PacketSocket, ParseError> {
+        // ffff8a5c4b6e1000 3      3    0003   2     1 0      0      28447
+        let mut chunks = s.split_whitespace();
+        let mut next = |field| chunks.next().ok_or_else(|| ParseError::missing(field, s));
+
+        let sk = next("sk")?.to_owned();
+        let ref_count = to_field(Some(next("RefCnt")?), "RefCnt", s)?;
+        let type_ = to_field(Some(next("Type")?), "Type", s)?;
+        let proto =
+            u16::from_str_radix(next("Proto")?, 16).map_err(|_| ParseError::invalid("Proto", s))?;
+
+        Ok(PacketSocket {
+            sk,
+            ref_count,
+            type_,
+            proto,
+            iface: to_field(Some(next("Iface")?), "Iface", s)?,
+            r: to_field(Some(next("R")?), "R", s)?,
+            rmem: to_field(Some(next("Rmem")?), "Rmem", s)?,
+            user: to_field(Some(next("User")?), "User", s)?,
+            inode: to_field(Some(next("Inode")?), "Inode", s)?,
+        })
+    }
+}
+
+/// Represents the output of `cat /proc/schedstat`
+///
+/// The layout is version-dependent; check `version` before relying on
+/// anything beyond the per-CPU run/wait/timeslice columns. `domainN` lines
+/// are skipped.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SchedStat {
+    pub version: u32,
+    /// Jiffies at the time of the read.
+    pub timestamp: u64,
+    pub cpus: Vec<SchedStatCpu>,
+}
+
+/// Scheduler statistics of one `cpuN` line of `/proc/schedstat`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SchedStatCpu {
+    pub cpu: u32,
+    /// Time spent running tasks, in nanoseconds.
+    pub run_time: u64,
+    /// Time tasks spent waiting to run, in nanoseconds.
+    pub wait_time: u64,
+    /// Number of timeslices run on this CPU.
+    pub timeslices: u64,
+}
+
+impl FromStr for SchedStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<SchedStat, ParseError> {
+        let mut schedstat: SchedStat = Default::default();
+
+        for line in s.lines() {
+            let mut chunks = line.split_whitespace();
+            let label = match chunks.next() {
+                Some(label) => label,
+                None => continue,
+            };
+
+            if label == "version" {
+                schedstat.version = to_field(chunks.next(), "version", line)?;
+            } else if label == "timestamp" {
+                schedstat.timestamp = to_field(chunks.next(), "timestamp", line)?;
+            } else if let Some(cpu) = label.strip_prefix("cpu") {
+                // cpu0 0 0 1934 612 1037 420 2745638361 412556396 1322
+                // The last three columns are run time, wait time and
+                // timeslices in every version since 10.
+                let values = to_vecu64(line)?;
+                let tail = match values.len().checked_sub(3) {
+                    Some(start) => &values[start..],
+                    None => return Err(ParseError::missing("pcount", line)),
+                };
+
+                schedstat.cpus.push(SchedStatCpu {
+                    cpu: to_field(Some(cpu), "cpu", line)?,
+                    run_time: tail[0],
+                    wait_time: tail[1],
+                    timeslices: tail[2],
+                });
+            }
+        }
+
+        Ok(schedstat)
+    }
+}
+
+/// Represents a line (netlink socket) in output of `cat /proc/net/netlink`
+///
+/// `sk` and `groups` are printed in hex; every other column is decimal.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetlinkSocket {
+    /// Kernel address of the socket, kept as the raw hex string.
+    pub sk: String,
+    /// Netlink protocol family, e.g. 0 (`NETLINK_ROUTE`).
+    pub eth: u32,
+    /// Port id the socket is bound to, usually the owning process's pid.
+    pub pid: u32,
+    /// Subscribed multicast groups, kept as the raw hex bitmask.
+    pub groups: String,
+    pub rmem: u32,
+    pub wmem: u32,
+    /// 1 while a dump is in progress.
+    pub dump: u32,
+    pub locks: u32,
+    pub drops: u32,
     pub inode: u64,
 }
 
-pub fn stat() -> io::Result<Stat> {
-    read_file("/proc/stat")?
-        .parse()
-        .map_err(|_| panic!("Infallible result occured"))
+impl FromStr for NetlinkSocket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<NetlinkSocket, ParseError> {
+        // ffff9e0b8a3c6000 0   1          00000550 0        0        0     2        0        18093
+        let mut chunks = s.split_whitespace();
+        let mut next = |field| chunks.next().ok_or_else(|| ParseError::missing(field, s));
+
+        let sk = next("sk")?.to_owned();
+        let eth = to_field(Some(next("Eth")?), "Eth", s)?;
+        let pid = to_field(Some(next("Pid")?), "Pid", s)?;
+        let groups = next("Groups")?.to_owned();
+
+        Ok(NetlinkSocket {
+            sk,
+            eth,
+            pid,
+            groups,
+            rmem: to_field(Some(next("Rmem")?), "Rmem", s)?,
+            wmem: to_field(Some(next("Wmem")?), "Wmem", s)?,
+            dump: to_field(Some(next("Dump")?), "Dump", s)?,
+            locks: to_field(Some(next("Locks")?), "Locks", s)?,
+            drops: to_field(Some(next("Drops")?), "Drops", s)?,
+            inode: to_field(Some(next("Inode")?), "Inode", s)?,
+        })
+    }
+}
+
+/// Represents a line (packet handler) in output of `cat /proc/net/ptype`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PacketType {
+    /// Device the handler is bound to. The column is blank for handlers
+    /// receiving from every device, which is `None`.
+    pub device: Option<String>,
+    /// Ethernet protocol in hex, e.g. `0800`, or `ALL` for `ETH_P_ALL`.
+    pub type_: String,
+    /// Receive function, e.g. `ip_rcv+0x0/0x3c0`.
+    pub function: String,
+}
+
+impl FromStr for PacketType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<PacketType, ParseError> {
+        // ALL  eth0     packet_rcv+0x0/0x60
+        // 0800          ip_rcv+0x0/0x3c0
+        let chunks = s.split_whitespace().collect::<Vec<&str>>();
+        let (type_, device, function) = match chunks[..] {
+            [type_, function] => (type_, None, function),
+            [type_, device, function] => (type_, Some(device), function),
+            [] => return Err(ParseError::missing("Type", s)),
+            [_] => return Err(ParseError::missing("Function", s)),
+            _ => return Err(ParseError::invalid("Device", s)),
+        };
+
+        Ok(PacketType {
+            device: device.map(str::to_owned),
+            type_: type_.to_owned(),
+            function: function.to_owned(),
+        })
+    }
+}
+
+/// Represents the output of `cat /proc/mdstat`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MdStat {
+    /// RAID levels supported by the kernel, e.g. `raid1`.
+    pub personalities: Vec<String>,
+    pub arrays: Vec<MdArray>,
+}
+
+/// A software RAID array of `/proc/mdstat`, with its continuation lines
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MdArray {
+    /// e.g. `md0`.
+    pub name: String,
+    /// `active` or `inactive`.
+    pub state: String,
+    /// e.g. `raid5`. Absent for inactive arrays.
+    pub level: Option<String>,
+    /// Member devices and their role number, e.g. `("sda1", 0)`.
+    pub devices: Vec<(String, usize)>,
+    /// Member health, one character per slot: `U` when up, `_` when missing
+    /// or failed. Empty if the kernel printed none.
+    pub status: String,
+    /// Percentage done of a running resync, recovery, reshape or check.
+    pub sync_progress: Option<f64>,
+}
+
+impl MdArray {
+    /// Whether any member slot is missing or failed.
+    pub fn is_degraded(&self) -> bool {
+        self.status.contains('_')
+    }
+}
+
+impl FromStr for MdStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<MdStat, ParseError> {
+        let mut mdstat: MdStat = Default::default();
+
+        for line in s.lines() {
+            if let Some(rest) = line.strip_prefix("Personalities :") {
+                mdstat.personalities = rest
+                    .split_whitespace()
+                    .map(|p| p.trim_matches(|c| c == '[' || c == ']').to_owned())
+                    .collect();
+            } else if line.starts_with(char::is_whitespace) {
+                // Continuation of the array above, e.g.
+                //       1953382400 blocks super 1.2 [2/1] [_U]
+                //       [=>...]  recovery = 27.3% (533598208/1953382400) ...
+                let array = match mdstat.arrays.last_mut() {
+                    Some(array) => array,
+                    None => continue,
+                };
+
+                for chunk in line.split_whitespace() {
+                    let inner = chunk.strip_prefix('[').and_then(|c| c.strip_suffix(']'));
+                    if let Some(status) = inner.filter(|i| i.chars().all(|c| c == 'U' || c == '_'))
+                    {
+                        array.status = status.to_owned();
+                    } else if let Some(percent) = chunk.strip_suffix('%') {
+                        let progress = to_field(Some(percent), "progress", line)?;
+                        array.sync_progress = Some(progress);
+                    }
+                }
+            } else if let Some((name, rest)) = line.split_once(" : ") {
+                if name == "unused devices" {
+                    continue;
+                }
+
+                // md0 : active raid5 sdd1[3] sdc1[1] sdb1[0](F)
+                let mut chunks = rest
+                    .split_whitespace()
+                    // e.g. `(auto-read-only)`
+                    .filter(|chunk| !chunk.starts_with('('))
+                    .peekable();
+                let state = chunks
+                    .next()
+                    .ok_or_else(|| ParseError::missing("state", line))?;
+                let level = chunks.next_if(|chunk| !chunk.contains('['));
+
+                let devices = chunks
+                    .map(|chunk| {
+                        let (device, role) = chunk
+                            .split_once('[')
+                            .ok_or_else(|| ParseError::invalid("device", line))?;
+                        let role = role.split(']').next();
+                        Ok((device.to_owned(), to_field(role, "device", line)?))
+                    })
+                    .collect::<Result<Vec<_>, ParseError>>()?;
+
+                mdstat.arrays.push(MdArray {
+                    name: name.trim().to_owned(),
+                    state: state.to_owned(),
+                    level: level.map(str::to_owned),
+                    devices,
+                    ..Default::default()
+                });
+            }
+        }
+
+        Ok(mdstat)
+    }
+}
+
+/// Represents the output of `cat /proc/net/rpc/nfsd`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NfsdStats {
+    /// Reply cache hits, from the `rc` line.
+    pub rc_hits: u64,
+    pub rc_misses: u64,
+    /// Requests that bypassed the reply cache.
+    pub rc_nocache: u64,
+    /// Stale file handles, from the `fh` line.
+    pub fh_stale: u64,
+    /// Bytes read from and written to disk, from the `io` line.
+    pub io_read: u64,
+    pub io_write: u64,
+    /// Number of nfsd threads, from the `th` line.
+    pub threads: u64,
+    /// Read-ahead cache size followed by depth hit counts, from the `ra` line
+    /// (removed in Linux 5.x).
+    pub ra: Vec<u64>,
+    /// Packets received, from the `net` line.
+    pub net_count: u64,
+    pub net_udp: u64,
+    pub net_tcp: u64,
+    pub net_tcp_conn: u64,
+    /// RPC calls received, from the `rpc` line.
+    pub rpc_count: u64,
+    pub rpc_bad: u64,
+    pub rpc_bad_fmt: u64,
+    pub rpc_bad_auth: u64,
+    pub rpc_bad_client: u64,
+    /// Per-operation call counts of each `procN` line, keyed by label, e.g.
+    /// `proc3` or `proc4ops`. The leading operation count is dropped.
+    pub procs: HashMap<String, Vec<u64>>,
+}
+
+impl FromStr for NfsdStats {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<NfsdStats, ParseError> {
+        let mut nfsd: NfsdStats = Default::default();
+
+        for line in s.lines() {
+            let label = match line.split_whitespace().next() {
+                Some(label) => label,
+                None => continue,
+            };
+
+            if label == "th" {
+                // Older kernels follow the thread count with a usage
+                // histogram of floats, which is not kept.
+                nfsd.threads = to_scalar(line, "th")?;
+                continue;
+            }
+
+            let values = to_vecu64(line)?;
+            let value = |i, field| {
+                values
+                    .get(i)
+                    .copied()
+                    .ok_or_else(|| ParseError::missing(field, line))
+            };
+
+            match label {
+                "rc" => {
+                    nfsd.rc_hits = value(0, "hits")?;
+                    nfsd.rc_misses = value(1, "misses")?;
+                    nfsd.rc_nocache = value(2, "nocache")?;
+                }
+                "fh" => nfsd.fh_stale = value(0, "stale")?,
+                "io" => {
+                    nfsd.io_read = value(0, "read")?;
+                    nfsd.io_write = value(1, "write")?;
+                }
+                "ra" => nfsd.ra = values,
+                "net" => {
+                    nfsd.net_count = value(0, "netcnt")?;
+                    nfsd.net_udp = value(1, "netudpcnt")?;
+                    nfsd.net_tcp = value(2, "nettcpcnt")?;
+                    nfsd.net_tcp_conn = value(3, "nettcpconn")?;
+                }
+                "rpc" => {
+                    nfsd.rpc_count = value(0, "rpccnt")?;
+                    nfsd.rpc_bad = value(1, "rpcbadcnt")?;
+                    nfsd.rpc_bad_fmt = value(2, "badfmt")?;
+                    nfsd.rpc_bad_auth = value(3, "badauth")?;
+                    nfsd.rpc_bad_client = value(4, "badclnt")?;
+                }
+                _ if label.starts_with("proc") => {
+                    // proc3 22 0 1 2 ...
+                    let counts = values.get(1..).unwrap_or(&[]).to_vec();
+                    nfsd.procs.insert(label.to_owned(), counts);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(nfsd)
+    }
+}
+
+/// Represents a line (CPU) in output of `cat /proc/net/softnet_stat`
+///
+/// Every column is hex. Older kernels omit `received_rps` and
+/// `flow_limit_count`, which are then zero.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SoftnetStat {
+    /// Packets processed by the CPU's backlog.
+    pub processed: u32,
+    /// Packets dropped because the backlog was full.
+    pub dropped: u32,
+    /// Times the NET_RX softirq ran out of budget or time with work left.
+    pub time_squeeze: u32,
+    pub cpu_collision: u32,
+    /// Times the CPU was woken to process packets steered by RPS.
+    pub received_rps: u32,
+    pub flow_limit_count: u32,
+}
+
+impl FromStr for SoftnetStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<SoftnetStat, ParseError> {
+        // 0004a1b2 00000000 00000003 00000000 00000000 00000000 00000000 00000000 00000000 00000010 00000000
+        let chunks = s.split_whitespace().collect::<Vec<&str>>();
+        let column = |i: usize, field| match chunks.get(i) {
+            Some(chunk) => {
+                u32::from_str_radix(chunk, 16).map_err(|_| ParseError::invalid(field, s))
+            }
+            None => Err(ParseError::missing(field, s)),
+        };
+        let optional = |i: usize, field| match chunks.get(i) {
+            Some(_) => column(i, field),
+            None => Ok(0),
+        };
+
+        Ok(SoftnetStat {
+            processed: column(0, "processed")?,
+            dropped: column(1, "dropped")?,
+            time_squeeze: column(2, "time_squeeze")?,
+            // Columns 3 - 7 are unused and always zero.
+            cpu_collision: column(8, "cpu_collision")?,
+            received_rps: optional(9, "received_rps")?,
+            flow_limit_count: optional(10, "flow_limit_count")?,
+        })
+    }
+}
+
+/// Represents a line (zone) in output of `cat /proc/buddyinfo`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BuddyInfo {
+    pub node: u32,
+    pub zone: String,
+    /// Free blocks of each order, starting at order 0 (a single page).
+    pub free_counts: Vec<u64>,
+}
+
+impl FromStr for BuddyInfo {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<BuddyInfo, ParseError> {
+        // Node 0, zone   Normal   10      5      3 ...
+        let mut chunks = s.split_whitespace();
+        if chunks.next() != Some("Node") {
+            return Err(ParseError::invalid("node", s));
+        }
+
+        let node = chunks.next().map(|chunk| chunk.trim_end_matches(','));
+        let node = to_field(node, "node", s)?;
+        if chunks.next() != Some("zone") {
+            return Err(ParseError::invalid("zone", s));
+        }
+
+        let zone = chunks
+            .next()
+            .ok_or_else(|| ParseError::missing("zone", s))?
+            .to_owned();
+        let free_counts = chunks
+            .map(|chunk| to_field(Some(chunk), "free_counts", s))
+            .collect::<Result<_, _>>()?;
+
+        Ok(BuddyInfo {
+            node,
+            zone,
+            free_counts,
+        })
+    }
+}
+
+/// System-wide metrics read back-to-back, so they describe roughly the same
+/// instant.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub stat: Stat,
+    pub meminfo: MemInfo,
+    pub loadavg: LoadAvg,
+    pub uptime: Uptime,
+    /// Taken immediately before the first file is read.
+    pub captured_at: Instant,
+}
+
+/// CPU, memory and swap utilization, each as a percentage (0.0 - 100.0).
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SystemLoad {
+    /// Busy time of all CPUs over the sampled interval.
+    pub cpu_percent: f64,
+    /// See `MemInfo::used_percent()`.
+    pub mem_percent: f64,
+    /// See `MemInfo::swap_used_percent()`.
+    pub swap_percent: f64,
+}
+
+/// Represents a line (partition) in output of `cat /proc/partitions`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Partition {
+    pub major: u32,
+    pub minor: u32,
+    /// Size in 1024-byte blocks.
+    pub blocks: u64,
+    pub name: String,
+}
+
+impl Partition {
+    pub fn size_bytes(&self) -> u64 {
+        self.blocks * 1024
+    }
+}
+
+impl FromStr for Partition {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Partition, ParseError> {
+        let mut chunks = s.split_whitespace();
+
+        Ok(Partition {
+            major: to_field(chunks.next(), "major", s)?,
+            minor: to_field(chunks.next(), "minor", s)?,
+            blocks: to_field(chunks.next(), "#blocks", s)?,
+            name: to_field(chunks.next(), "name", s)?,
+        })
+    }
+}
+
+/// Keeps a procfs file open and re-reads it into a reused buffer, avoiding
+/// an open/close and allocation on every poll.
+#[derive(Debug)]
+pub struct ProcfsCache<T> {
+    file: File,
+    buf: String,
+    value: T,
+}
+
+/// Cached reader for `/proc/stat`.
+pub type StatCache = ProcfsCache<Stat>;
+
+/// Cached reader for `/proc/meminfo`.
+pub type MemInfoCache = ProcfsCache<MemInfo>;
+
+impl<T: FromStr<Err = ParseError> + Default> ProcfsCache<T> {
+    fn open<P: AsRef<Path>>(path: P) -> io::Result<ProcfsCache<T>> {
+        Ok(ProcfsCache {
+            file: File::open(path)?,
+            buf: String::new(),
+            value: T::default(),
+        })
+    }
+
+    /// Re-reads the file from the start and returns the freshly parsed value.
+    pub fn refresh(&mut self) -> io::Result<&T> {
+        self.file.seek(SeekFrom::Start(0))?;
+        self.buf.clear();
+        self.file.read_to_string(&mut self.buf)?;
+        self.value = self.buf.parse()?;
+
+        Ok(&self.value)
+    }
+
+    /// Value parsed by the last successful `refresh()`, or the default if
+    /// there has been none.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Represents a line (neighbor) in output of `cat /proc/net/arp`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ArpEntry {
+    pub ip_address: Ipv4Addr,
+    /// `ARPHRD_*` hardware type, e.g. `0x1` for Ethernet.
+    pub hw_type: u16,
+    /// `ATF_*` flags, e.g. `0x2` for a completed entry.
+    pub flags: u16,
+    pub hw_address: String,
+    pub mask: String,
+    pub device: String,
+}
+
+impl FromStr for ArpEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<ArpEntry, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let mut next = |field| chunks.next().ok_or_else(|| ParseError::missing(field, s));
+        let hex = |chunk: &str, field| {
+            u16::from_str_radix(chunk.trim_start_matches("0x"), 16)
+                .map_err(|_| ParseError::invalid(field, s))
+        };
+
+        Ok(ArpEntry {
+            ip_address: to_field(Some(next("IP address")?), "IP address", s)?,
+            hw_type: hex(next("HW type")?, "HW type")?,
+            flags: hex(next("Flags")?, "Flags")?,
+            hw_address: next("HW address")?.to_owned(),
+            mask: next("Mask")?.to_owned(),
+            device: next("Device")?.to_owned(),
+        })
+    }
+}
+
+/// Represents a line (filesystem) in output of `cat /proc/filesystems`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Filesystem {
+    /// Whether the filesystem is not backed by a block device.
+    pub nodev: bool,
+    pub name: String,
+}
+
+impl FromStr for Filesystem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Filesystem, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let first = chunks
+            .next()
+            .ok_or_else(|| ParseError::missing("name", s))?;
+
+        let (nodev, name) = match first {
+            "nodev" => (
+                true,
+                chunks
+                    .next()
+                    .ok_or_else(|| ParseError::missing("name", s))?,
+            ),
+            name => (false, name),
+        };
+
+        Ok(Filesystem {
+            nodev,
+            name: name.to_owned(),
+        })
+    }
+}
+
+/// Represents a line (module) in output of `cat /proc/modules`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Module {
+    pub name: String,
+    /// Memory size of the module, in bytes.
+    pub size: u64,
+    pub ref_count: u32,
+    /// Modules depending on this one.
+    pub used_by: Vec<String>,
+    /// `Live`, `Loading` or `Unloading`.
+    pub state: String,
+    /// Load address; absent on kernels that do not print it.
+    pub offset: Option<u64>,
+}
+
+impl FromStr for Module {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Module, ParseError> {
+        // nf_nat 49152 2 nft_chain_nat,xt_MASQUERADE, Live 0xffffffffc0a5e000
+        let mut chunks = s.split_whitespace();
+
+        let name = to_field(chunks.next(), "name", s)?;
+        let size = to_field(chunks.next(), "size", s)?;
+        let ref_count = to_field(chunks.next(), "ref_count", s)?;
+        let used_by = match chunks.next() {
+            Some("-") => Vec::new(),
+            Some(list) => list
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(str::to_owned)
+                .collect(),
+            None => return Err(ParseError::missing("used_by", s)),
+        };
+        let state = to_field(chunks.next(), "state", s)?;
+        let offset = match chunks.next() {
+            Some(offset) => Some(
+                u64::from_str_radix(offset.trim_start_matches("0x"), 16)
+                    .map_err(|_| ParseError::invalid("offset", s))?,
+            ),
+            None => None,
+        };
+
+        Ok(Module {
+            name,
+            size,
+            ref_count,
+            used_by,
+            state,
+            offset,
+        })
+    }
+}
+
+/// Represents a multicast group membership in output of `cat /proc/net/igmp`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IgmpEntry {
+    /// Interface index.
+    pub index: u32,
+    pub device: String,
+    pub group: Ipv4Addr,
+    pub users: u32,
+    /// Raw `<running>:<expires>` report timer.
+    pub timer: String,
+    pub reporter: bool,
+}
+
+/// Groups are listed indented beneath the interface that joined them, so the
+/// current interface is carried down to each group line.
+fn to_igmp_entries(s: &str) -> Result<Vec<IgmpEntry>, ParseError> {
+    let mut entries = Vec::new();
+    let mut interface: Option<(u32, String)> = None;
+
+    // First line is the column header.
+    for line in s.lines().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            // 2	eth0      :     2      V3
+            let (head, _) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::missing("Device", line))?;
+            let mut chunks = head.split_whitespace();
+            let index = to_field(chunks.next(), "Idx", line)?;
+            let device = to_field(chunks.next(), "Device", line)?;
+            interface = Some((index, device));
+            continue;
+        }
+
+        // 				010000E0     1 0:00000000		0
+        let (index, device) = interface
+            .as_ref()
+            .ok_or_else(|| ParseError::missing("Device", line))?;
+        let mut chunks = line.split_whitespace();
+        let group = chunks
+            .next()
+            .ok_or_else(|| ParseError::missing("Group", line))?;
+        let users = to_field(chunks.next(), "Users", line)?;
+        let timer = to_field(chunks.next(), "Timer", line)?;
+        let reporter = match chunks.next() {
+            Some("0") => false,
+            Some("1") => true,
+            Some(_) => return Err(ParseError::invalid("Reporter", line)),
+            None => return Err(ParseError::missing("Reporter", line)),
+        };
+
+        entries.push(IgmpEntry {
+            index: *index,
+            device: device.clone(),
+            group: to_ipv4addr(group).ok_or_else(|| ParseError::invalid("Group", line))?,
+            users,
+            timer,
+            reporter,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Represents a zone block in output of `cat /proc/zoneinfo`
+///
+/// Page counts are in pages of `PAGE_SIZE` bytes.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ZoneInfo {
+    pub node: u32,
+    pub zone: String,
+    pub free_pages: u64,
+    pub min: u64,
+    pub low: u64,
+    pub high: u64,
+    /// The zone's `nr_*` counters. The per-node statistics printed under the
+    /// first zone of each node are not included.
+    pub counters: HashMap<String, u64>,
+}
+
+fn to_zoneinfos(s: &str) -> Result<Vec<ZoneInfo>, ParseError> {
+    let mut zones: Vec<ZoneInfo> = Vec::new();
+    let mut per_node = false;
+
+    for line in s.lines() {
+        let mut chunks = line.split_whitespace();
+        let key = match chunks.next() {
+            Some(key) => key,
+            None => continue,
+        };
+
+        if key == "Node" {
+            // Node 0, zone   Normal
+            let node = chunks.next().map(|chunk| chunk.trim_end_matches(','));
+            let node = to_field(node, "node", line)?;
+            if chunks.next() != Some("zone") {
+                return Err(ParseError::invalid("zone", line));
+            }
+
+            zones.push(ZoneInfo {
+                node,
+                zone: to_field(chunks.next(), "zone", line)?,
+                ..Default::default()
+            });
+            per_node = false;
+            continue;
+        }
+
+        let zone = zones
+            .last_mut()
+            .ok_or_else(|| ParseError::missing("zone", line))?;
+        match key {
+            "per-node" => per_node = true,
+            "pages" => {
+                // pages free     3840
+                per_node = false;
+                chunks.next();
+                zone.free_pages = to_field(chunks.next(), "pages free", line)?;
+            }
+            "min" => zone.min = to_field(chunks.next(), "min", line)?,
+            "low" => zone.low = to_field(chunks.next(), "low", line)?,
+            "high" => zone.high = to_field(chunks.next(), "high", line)?,
+            _ if key.starts_with("nr_") && !per_node => {
+                let value = to_field(chunks.next(), "value", line)?;
+                zone.counters.insert(key.to_owned(), value);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(zones)
+}
+
+/// Represents a line (protocol) in output of `cat /proc/net/protocols`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Protocol {
+    pub name: String,
+    /// Size in bytes of the protocol's socket structure.
+    pub size: u32,
+    /// Sockets in use, or -1 if the protocol does not count them.
+    pub sockets: i64,
+    /// Memory in use, in pages, or -1 if the protocol does not account it.
+    pub memory: i64,
+    /// Whether memory pressure is on; `None` when not implemented (`NI`).
+    pub press: Option<bool>,
+    pub maxhdr: u32,
+    pub slab: bool,
+    pub module: String,
+}
+
+impl FromStr for Protocol {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Protocol, ParseError> {
+        let mut chunks = s.split_whitespace();
+        let yes_no = |chunk: Option<&str>, field| match chunk {
+            Some("yes") => Ok(true),
+            Some("no") => Ok(false),
+            Some(_) => Err(ParseError::invalid(field, s)),
+            None => Err(ParseError::missing(field, s)),
+        };
+
+        let name = to_field(chunks.next(), "protocol", s)?;
+        let size = to_field(chunks.next(), "size", s)?;
+        let sockets = to_field(chunks.next(), "sockets", s)?;
+        let memory = to_field(chunks.next(), "memory", s)?;
+        let press = match chunks.next() {
+            Some("NI") => None,
+            chunk => Some(yes_no(chunk, "press")?),
+        };
+
+        Ok(Protocol {
+            name,
+            size,
+            sockets,
+            memory,
+            press,
+            maxhdr: to_field(chunks.next(), "maxhdr", s)?,
+            slab: yes_no(chunks.next(), "slab")?,
+            module: to_field(chunks.next(), "module", s)?,
+        })
+    }
+}
+
+/// Represents a line (interface) in output of `cat /proc/net/wireless`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Wireless {
+    pub interface: String,
+    /// Driver-specific status word, printed in hex.
+    pub status: u32,
+    pub link_quality: f64,
+    /// Signal level, usually in dBm.
+    pub signal_level: f64,
+    /// Noise level, usually in dBm.
+    pub noise_level: f64,
+    pub discarded_nwid: u64,
+    pub discarded_crypt: u64,
+    pub missed_beacon: u64,
+}
+
+impl FromStr for Wireless {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Wireless, ParseError> {
+        //  wlan0: 0000   70.  -40.  -256        0      0      0      0     12        0
+        let (interface, rest) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::missing("face", s))?;
+        let mut chunks = rest.split_whitespace();
+        let mut next = |field| chunks.next().ok_or_else(|| ParseError::missing(field, s));
+        // Levels may be printed with a trailing `.` when updated, e.g. `70.`.
+        let level = |chunk: &str, field| {
+            chunk
+                .trim_end_matches('.')
+                .parse::<f64>()
+                .map_err(|_| ParseError::invalid(field, s))
+        };
+
+        let status = u32::from_str_radix(next("status")?, 16)
+            .map_err(|_| ParseError::invalid("status", s))?;
+        let link_quality = level(next("link")?, "link")?;
+        let signal_level = level(next("level")?, "level")?;
+        let noise_level = level(next("noise")?, "noise")?;
+        let discarded_nwid = to_field(Some(next("nwid")?), "nwid", s)?;
+        let discarded_crypt = to_field(Some(next("crypt")?), "crypt", s)?;
+        // frag, retry and misc are not kept.
+        next("frag")?;
+        next("retry")?;
+        next("misc")?;
+
+        Ok(Wireless {
+            interface: interface.trim().to_owned(),
+            status,
+            link_quality,
+            signal_level,
+            noise_level,
+            discarded_nwid,
+            discarded_crypt,
+            missed_beacon: to_field(Some(next("beacon")?), "beacon", s)?,
+        })
+    }
+}
+
+/// Represents the output of `cat /proc/net/snmp`
+///
+/// Counters are grouped by protocol (`Ip`, `Icmp`, `IcmpMsg`, `Tcp`, `Udp`,
+/// `UdpLite`) and keyed by their SNMP MIB name. Values are signed because
+/// `Tcp: MaxConn` is reported as -1 when unlimited.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Snmp {
+    pub protocols: HashMap<String, HashMap<String, i64>>,
+}
+
+impl Snmp {
+    /// Value of an arbitrary counter, e.g. `get("Udp", "InErrors")`.
+    pub fn get(&self, protocol: &str, key: &str) -> Option<i64> {
+        self.protocols.get(protocol)?.get(key).copied()
+    }
+
+    pub fn ip_in_receives(&self) -> Option<u64> {
+        self.counter("Ip", "InReceives")
+    }
+
+    pub fn ip_out_requests(&self) -> Option<u64> {
+        self.counter("Ip", "OutRequests")
+    }
+
+    pub fn tcp_active_opens(&self) -> Option<u64> {
+        self.counter("Tcp", "ActiveOpens")
+    }
+
+    pub fn tcp_curr_estab(&self) -> Option<u64> {
+        self.counter("Tcp", "CurrEstab")
+    }
+
+    pub fn tcp_retrans_segs(&self) -> Option<u64> {
+        self.counter("Tcp", "RetransSegs")
+    }
+
+    pub fn udp_in_datagrams(&self) -> Option<u64> {
+        self.counter("Udp", "InDatagrams")
+    }
+
+    pub fn udp_in_errors(&self) -> Option<u64> {
+        self.counter("Udp", "InErrors")
+    }
+
+    fn counter(&self, protocol: &str, key: &str) -> Option<u64> {
+        self.get(protocol, key)
+            .and_then(|value| u64::try_from(value).ok())
+    }
+}
+
+impl FromStr for Snmp {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Snmp, ParseError> {
+        Ok(Snmp {
+            protocols: to_paired_tables(s)?,
+        })
+    }
+}
+
+/// Represents the output of `cat /proc/net/netstat`
+///
+/// Counters are grouped by category (`TcpExt`, `IpExt`, and `MPTcpExt` on
+/// newer kernels) and keyed by their kernel name.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetStat {
+    pub categories: HashMap<String, HashMap<String, u64>>,
+}
+
+impl NetStat {
+    /// Value of an arbitrary counter, e.g. `get("TcpExt", "TCPTimeouts")`.
+    pub fn get(&self, category: &str, key: &str) -> Option<u64> {
+        self.categories.get(category)?.get(key).copied()
+    }
+
+    pub fn tcp_syn_retrans(&self) -> Option<u64> {
+        self.get("TcpExt", "TCPSynRetrans")
+    }
+
+    pub fn tcp_lost_retransmit(&self) -> Option<u64> {
+        self.get("TcpExt", "TCPLostRetransmit")
+    }
+
+    pub fn listen_overflows(&self) -> Option<u64> {
+        self.get("TcpExt", "ListenOverflows")
+    }
+
+    pub fn listen_drops(&self) -> Option<u64> {
+        self.get("TcpExt", "ListenDrops")
+    }
+
+    pub fn ip_in_octets(&self) -> Option<u64> {
+        self.get("IpExt", "InOctets")
+    }
+
+    pub fn ip_out_octets(&self) -> Option<u64> {
+        self.get("IpExt", "OutOctets")
+    }
+}
+
+impl FromStr for NetStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<NetStat, ParseError> {
+        Ok(NetStat {
+            categories: to_paired_tables(s)?,
+        })
+    }
+}
+
+/// Represents the output of `cat /proc/net/snmp6`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Snmp6 {
+    pub values: HashMap<String, u64>,
+}
+
+impl Snmp6 {
+    /// Value of an arbitrary counter, e.g. `get("Udp6InErrors")`.
+    pub fn get(&self, key: &str) -> Option<u64> {
+        self.values.get(key).copied()
+    }
+
+    pub fn ip6_in_receives(&self) -> Option<u64> {
+        self.get("Ip6InReceives")
+    }
+
+    pub fn ip6_out_requests(&self) -> Option<u64> {
+        self.get("Ip6OutRequests")
+    }
+
+    pub fn icmp6_in_msgs(&self) -> Option<u64> {
+        self.get("Icmp6InMsgs")
+    }
+
+    pub fn icmp6_out_msgs(&self) -> Option<u64> {
+        self.get("Icmp6OutMsgs")
+    }
+
+    pub fn udp6_in_datagrams(&self) -> Option<u64> {
+        self.get("Udp6InDatagrams")
+    }
+}
+
+impl FromStr for Snmp6 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Snmp6, ParseError> {
+        let mut snmp6: Snmp6 = Default::default();
+
+        for line in s.lines() {
+            let mut chunks = line.split_whitespace();
+            let key = match chunks.next() {
+                Some(key) => key,
+                None => continue,
+            };
+
+            let value = to_field(chunks.next(), "value", line)?;
+            snmp6.values.insert(key.to_owned(), value);
+        }
+
+        Ok(snmp6)
+    }
+}
+
+/// Represents the output of `cat /proc/net/sockstat`
+///
+/// `tcp_mem` and `udp_mem` are in pages.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SockStat {
+    pub sockets_used: u64,
+    pub tcp_inuse: u64,
+    pub tcp_orphan: u64,
+    pub tcp_tw: u64,
+    pub tcp_alloc: u64,
+    pub tcp_mem: u64,
+    pub udp_inuse: u64,
+    pub udp_mem: u64,
+}
+
+impl FromStr for SockStat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<SockStat, ParseError> {
+        let mut sockstat: SockStat = Default::default();
+
+        for line in s.lines() {
+            // TCP: inuse 23 orphan 0 tw 4 alloc 31 mem 6
+            let (label, rest) = match to_labeled(line) {
+                Some(pair) => pair,
+                None => continue,
+            };
+
+            let mut chunks = rest.split_whitespace();
+            while let Some(key) = chunks.next() {
+                let value = to_field(chunks.next(), "value", line)?;
+                match (label, key) {
+                    ("sockets", "used") => sockstat.sockets_used = value,
+                    ("TCP", "inuse") => sockstat.tcp_inuse = value,
+                    ("TCP", "orphan") => sockstat.tcp_orphan = value,
+                    ("TCP", "tw") => sockstat.tcp_tw = value,
+                    ("TCP", "alloc") => sockstat.tcp_alloc = value,
+                    ("TCP", "mem") => sockstat.tcp_mem = value,
+                    ("UDP", "inuse") => sockstat.udp_inuse = value,
+                    ("UDP", "mem") => sockstat.udp_mem = value,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(sockstat)
+    }
+}
+
+/// Parses the `Label: name name ...` / `Label: value value ...` row pairs used
+/// by `/proc/net/snmp` and `/proc/net/netstat`.
+fn to_paired_tables<T: FromStr>(
+    s: &str,
+) -> Result<HashMap<String, HashMap<String, T>>, ParseError> {
+    let mut tables = HashMap::new();
+    let mut lines = s.lines().filter(|line| !line.trim().is_empty());
+
+    while let Some(names) = lines.next() {
+        let values = lines
+            .next()
+            .ok_or_else(|| ParseError::missing("values", names))?;
+        let (label, names) =
+            to_labeled(names).ok_or_else(|| ParseError::invalid("label", names))?;
+        match to_labeled(values) {
+            Some((value_label, _)) if value_label == label => {}
+            _ => return Err(ParseError::invalid("label", values)),
+        }
+
+        let mut chunks = values.split_whitespace().skip(1);
+        let mut table = HashMap::new();
+        for name in names.split_whitespace() {
+            table.insert(name.to_owned(), to_field(chunks.next(), "value", values)?);
+        }
+
+        tables.insert(label.to_owned(), table);
+    }
+
+    Ok(tables)
+}
+
+/// Splits `Label: rest` into its label and the remainder.
+fn to_labeled(line: &str) -> Option<(&str, &str)> {
+    let colon = line.find(':')?;
+    Some((&line[..colon], &line[colon + 1..]))
+}
+
+/// Represents TCP socket's state.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SocketState {
+    Established = 1,
+    SynSent = 2,
+    SynRecv = 3,
+    FinWait1 = 4,
+    FinWait2 = 5,
+    TimeWait = 6,
+    Close = 7,
+    CloseWait = 8,
+    LastAck = 9,
+    Listen = 10,
+    Closing = 11,
+}
+
+impl SocketState {
+    pub fn is_listening(&self) -> bool {
+        *self == SocketState::Listen
+    }
+
+    pub fn is_connected(&self) -> bool {
+        *self == SocketState::Established
+    }
+
+    /// Whether the connection is being set up (`SYN_SENT` or `SYN_RECV`).
+    pub fn is_connecting(&self) -> bool {
+        matches!(self, SocketState::SynSent | SocketState::SynRecv)
+    }
+
+    /// Whether the connection is being torn down by either side.
+    pub fn is_closing(&self) -> bool {
+        matches!(
+            self,
+            SocketState::FinWait1
+                | SocketState::FinWait2
+                | SocketState::Closing
+                | SocketState::LastAck
+                | SocketState::TimeWait
+                | SocketState::CloseWait
+        )
+    }
+}
+
+impl TryFrom<u8> for SocketState {
+    type Error = ParseError;
+
+    /// Decodes the `st` column of the socket tables, e.g. `0x0A` for LISTEN.
+    fn try_from(value: u8) -> Result<SocketState, ParseError> {
+        Ok(match value {
+            1 => SocketState::Established,
+            2 => SocketState::SynSent,
+            3 => SocketState::SynRecv,
+            4 => SocketState::FinWait1,
+            5 => SocketState::FinWait2,
+            6 => SocketState::TimeWait,
+            7 => SocketState::Close,
+            8 => SocketState::CloseWait,
+            9 => SocketState::LastAck,
+            10 => SocketState::Listen,
+            11 => SocketState::Closing,
+            _ => return Err(ParseError::invalid("st", &format!("{:02X}", value))),
+        })
+    }
+}
+
+impl fmt::Display for SocketState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            SocketState::Established => "ESTABLISHED",
+            SocketState::SynSent => "SYN_SENT",
+            SocketState::SynRecv => "SYN_RECV",
+            SocketState::FinWait1 => "FIN_WAIT1",
+            SocketState::FinWait2 => "FIN_WAIT2",
+            SocketState::TimeWait => "TIME_WAIT",
+            SocketState::Close => "CLOSE",
+            SocketState::CloseWait => "CLOSE_WAIT",
+            SocketState::LastAck => "LAST_ACK",
+            SocketState::Listen => "LISTEN",
+            SocketState::Closing => "CLOSING",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Represents TCP socket's timer status, with the time until it expires.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SocketTimerState {
+    Inactive,
+    Retransmit(Duration),
+    Keepalive(Duration),
+    TimeWait(Duration),
+    Probe(Duration),
+}
+
+impl SocketTimerState {
+    /// Time until the timer expires, or `None` when no timer is pending.
+    pub fn timeout(&self) -> Option<Duration> {
+        match *self {
+            SocketTimerState::Inactive => None,
+            SocketTimerState::Retransmit(when)
+            | SocketTimerState::Keepalive(when)
+            | SocketTimerState::TimeWait(when)
+            | SocketTimerState::Probe(when) => Some(when),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        *self != SocketTimerState::Inactive
+    }
+}
+
+/// Represents a line (socket) in output of `cat /proc/net/{tcp,udp,tcp6,udp6}`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Socket {
+    pub sl: u64,
+    pub local_address: IpAddr,
+    pub local_port: u16,
+    pub remote_address: IpAddr,
+    pub remote_port: u16,
+    pub state: SocketState,
+    pub tx_queue: u64,
+    pub rx_queue: u64,
+    pub timer: SocketTimerState,
+    /// Unrecovered retransmission timeouts.
+    pub retransmits: u64,
+    pub uid: u32,
+    /// Unanswered zero-window probes.
+    pub timeout: u64,
+    pub inode: u64,
+    pub ref_count: u32,
+    /// Congestion window, in segments. `None` for UDP lines, and for TCP
+    /// TIME_WAIT and SYN_RECV request sockets, whose rows omit it.
+    pub cwnd: Option<u32>,
+    /// Slow-start threshold, or -1 while still in initial slow start. `None`
+    /// whenever `cwnd` is.
+    pub ssthresh: Option<i32>,
+}
+
+impl Socket {
+    /// Whether the remote end is the wildcard `0.0.0.0:0` (or `[::]:0`), as
+    /// for listening and unconnected sockets.
+    pub fn is_wildcard(&self) -> bool {
+        self.remote_address.is_unspecified() && self.remote_port == 0
+    }
+
+    /// Name of the user owning the socket, or `None` if `uid` has no entry.
+    ///
+    /// With the `users` feature this asks the system user database via
+    /// `getpwuid_r`; otherwise `/etc/passwd` is read directly.
+    pub fn username(&self) -> io::Result<Option<String>> {
+        username_for(self.uid)
+    }
+}
+
+impl FromStr for Socket {
+    type Err = ParseError;
+
+    /// Parses a single row, without the header. See `parse_socket()`.
+    fn from_str(s: &str) -> Result<Socket, ParseError> {
+        to_net_socket(s)
+    }
+}
+
+impl fmt::Display for Socket {
+    /// Formats as `local -> remote (STATE)`, e.g.
+    /// `127.0.0.1:4402 -> 46.238.65.91:80 (ESTABLISHED)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {} ({})",
+            SocketAddr::new(self.local_address, self.local_port),
+            SocketAddr::new(self.remote_address, self.remote_port),
+            self.state
+        )
+    }
+}
+
+/// Sockets of one `/proc/net/{tcp,udp,tcp6,udp6}` read, with the time of the
+/// read, so two snapshots can be diffed over a known interval.
+#[derive(Debug, Clone)]
+pub struct SocketSnapshot {
+    pub sockets: Vec<Socket>,
+    /// Taken immediately before the file is read.
+    pub read_at: Instant,
+}
+
+impl SocketSnapshot {
+    /// Time elapsed between `previous` and this snapshot, or zero if
+    /// `previous` was taken later.
+    pub fn interval_since(&self, previous: &SocketSnapshot) -> Duration {
+        self.read_at.saturating_duration_since(previous.read_at)
+    }
+}
+
+/// Lazily parses the rows of `/proc/net/{tcp,udp,tcp6,udp6}`, reading the
+/// file through a buffer as the iterator is consumed.
+///
+/// A row that fails to parse yields an `InvalidData` error and iteration
+/// continues. An I/O error while reading, including a line that is not valid
+/// UTF-8, is yielded once and then ends the iteration.
+#[derive(Debug)]
+pub struct SocketIter {
+    lines: io::Lines<BufReader<File>>,
+    failed: bool,
+}
+
+impl SocketIter {
+    fn open<P: AsRef<Path>>(path: P) -> io::Result<SocketIter> {
+        let mut lines = BufReader::new(File::open(path)?).lines();
+
+        // First line is the column header.
+        lines.next().transpose()?;
+
+        Ok(SocketIter {
+            lines,
+            failed: false,
+        })
+    }
+}
+
+impl Iterator for SocketIter {
+    type Item = io::Result<Socket>;
+
+    fn next(&mut self) -> Option<io::Result<Socket>> {
+        if self.failed {
+            return None;
+        }
+
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            };
+
+            if !line.trim().is_empty() {
+                return Some(to_net_socket(&line).map_err(io::Error::from));
+            }
+        }
+    }
+}
+
+/// Represents a line (socket) in output of `cat /proc/net/unix`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnixSocket {
+    pub ref_count: u32,
+    pub protocol: u32,
+    pub flags: u32,
+    pub socket_type: u16,
+    pub state: u8,
+    pub inode: u64,
+    /// Bound path; abstract sockets start with `@`, unbound ones have none.
+    pub path: Option<String>,
+}
+
+impl FromStr for UnixSocket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<UnixSocket, ParseError> {
+        let mut rest = s.trim_start();
+        let mut next = |field| {
+            let chunk = rest.split_whitespace().next();
+            rest = rest[chunk.map_or(rest.len(), str::len)..].trim_start();
+            chunk.ok_or_else(|| ParseError::missing(field, s))
+        };
+        let invalid = |field| ParseError::invalid(field, s);
+
+        // Num - kernel address, unused
+        next("Num")?;
+        let ref_count =
+            u32::from_str_radix(next("RefCount")?, 16).map_err(|_| invalid("RefCount"))?;
+        let protocol =
+            u32::from_str_radix(next("Protocol")?, 16).map_err(|_| invalid("Protocol"))?;
+        let flags = u32::from_str_radix(next("Flags")?, 16).map_err(|_| invalid("Flags"))?;
+        let socket_type = u16::from_str_radix(next("Type")?, 16).map_err(|_| invalid("Type"))?;
+        let state = u8::from_str_radix(next("St")?, 16).map_err(|_| invalid("St"))?;
+        let inode = next("Inode")?
+            .parse::<u64>()
+            .map_err(|_| invalid("Inode"))?;
+
+        // Whatever remains is the path, which may itself contain spaces.
+        Ok(UnixSocket {
+            ref_count,
+            protocol,
+            flags,
+            socket_type,
+            state,
+            inode,
+            path: match rest.trim_end() {
+                "" => None,
+                path => Some(path.to_owned()),
+            },
+        })
+    }
+}
+
+/// Reads procfs reports relative to a configurable root directory.
+///
+/// The free functions in this crate read from `/proc`; a `ProcfsReader` can
+/// instead point at a container's procfs bind-mounted elsewhere, or at a
+/// directory of captured sample files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcfsReader {
+    root: PathBuf,
+}
+
+impl Default for ProcfsReader {
+    fn default() -> ProcfsReader {
+        ProcfsReader::from_root("/proc")
+    }
+}
+
+impl ProcfsReader {
+    /// Creates a reader that resolves every file relative to `root`.
+    pub fn from_root<P: AsRef<Path>>(root: P) -> ProcfsReader {
+        ProcfsReader {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Creates a reader over a captured bundle of procfs files, e.g. an
+    /// unpacked tarball attached to a bug report.
+    ///
+    /// Files must keep their procfs layout relative to `dir`: `stat`,
+    /// `meminfo`, `net/tcp`, `pressure/cpu`, `[pid]/stat` and so on. A reader
+    /// whose file is absent from the bundle fails with `NotFound`. This is
+    /// the same as `from_root()`, named for intent.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> ProcfsReader {
+        ProcfsReader::from_root(dir)
+    }
+
+    /// Directory this reader treats as the procfs mount point.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn stat(&self) -> io::Result<Stat> {
+        Ok(self.read("stat")?.parse()?)
+    }
+
+    /// Like `stat()`, but leaves `cpus` empty instead of parsing every
+    /// `cpuN` line.
+    pub fn stat_aggregate_only(&self) -> io::Result<Stat> {
+        let opts = ParseOptions::default().aggregate_only();
+        Ok(Stat::from_str_with(&self.read("stat")?, opts)?)
+    }
+
+    /// Fraction of time (0.0 - 1.0) all CPUs were busy over `interval`.
+    ///
+    /// Blocks the calling thread for `interval`.
+    pub fn cpu_usage_over(&self, interval: Duration) -> io::Result<f64> {
+        let previous = self.stat()?;
+        thread::sleep(interval);
+        Ok(self.stat()?.cpu_usage_since(&previous))
+    }
+
+    /// CPU usage over `interval`, plus memory and swap usage read once at
+    /// the end of it.
+    ///
+    /// Blocks the calling thread for `interval`.
+    pub fn system_load(&self, interval: Duration) -> io::Result<SystemLoad> {
+        let cpu = self.cpu_usage_over(interval)?;
+        let meminfo = self.meminfo()?;
+
+        Ok(SystemLoad {
+            cpu_percent: cpu * 100.0,
+            mem_percent: meminfo.used_percent(),
+            swap_percent: meminfo.swap_used_percent(),
+        })
+    }
+
+    /// Per-core busy fraction over `interval`.
+    ///
+    /// Blocks the calling thread for `interval`.
+    pub fn cpus_usage_over(&self, interval: Duration) -> io::Result<Vec<f64>> {
+        let previous = self.stat()?;
+        thread::sleep(interval);
+        Ok(self.stat()?.cpus_usage_since(&previous))
+    }
+
+    /// Spawns a thread sending the busy fraction of all CPUs over each
+    /// `interval`.
+    ///
+    /// The thread stops once the receiver is dropped, or when reading `stat`
+    /// fails, in which case the error is returned through the handle.
+    pub fn watch_cpu(&self, interval: Duration) -> (JoinHandle<io::Result<()>>, Receiver<f64>) {
+        let (tx, rx) = mpsc::channel();
+        let reader = self.clone();
+
+        let handle = thread::spawn(move || {
+            let mut previous = reader.stat()?;
+            loop {
+                thread::sleep(interval);
+                let current = reader.stat()?;
+                if tx.send(current.cpu_usage_since(&previous)).is_err() {
+                    return Ok(());
+                }
+                previous = current;
+            }
+        });
+
+        (handle, rx)
+    }
+
+    pub fn meminfo(&self) -> io::Result<MemInfo> {
+        Ok(self.read("meminfo")?.parse()?)
+    }
+
+    pub fn tcp(&self) -> io::Result<Vec<Socket>> {
+        net(&self.read("net/tcp")?)
+    }
+
+    pub fn udp(&self) -> io::Result<Vec<Socket>> {
+        net(&self.read("net/udp")?)
+    }
+
+    pub fn tcp6(&self) -> io::Result<Vec<Socket>> {
+        net(&self.read("net/tcp6")?)
+    }
+
+    pub fn udp6(&self) -> io::Result<Vec<Socket>> {
+        net(&self.read("net/udp6")?)
+    }
+
+    pub fn tcp_snapshot(&self) -> io::Result<SocketSnapshot> {
+        self.socket_snapshot("net/tcp")
+    }
+
+    pub fn udp_snapshot(&self) -> io::Result<SocketSnapshot> {
+        self.socket_snapshot("net/udp")
+    }
+
+    pub fn tcp6_snapshot(&self) -> io::Result<SocketSnapshot> {
+        self.socket_snapshot("net/tcp6")
+    }
+
+    pub fn udp6_snapshot(&self) -> io::Result<SocketSnapshot> {
+        self.socket_snapshot("net/udp6")
+    }
+
+    fn socket_snapshot(&self, file: &str) -> io::Result<SocketSnapshot> {
+        let read_at = Instant::now();
+
+        Ok(SocketSnapshot {
+            sockets: net(&self.read(file)?)?,
+            read_at,
+        })
+    }
+
+    pub fn tcp_filtered(&self, states: &[SocketState]) -> io::Result<Vec<Socket>> {
+        net_filtered(&self.read("net/tcp")?, states)
+    }
+
+    pub fn udp_filtered(&self, states: &[SocketState]) -> io::Result<Vec<Socket>> {
+        net_filtered(&self.read("net/udp")?, states)
+    }
+
+    pub fn tcp6_filtered(&self, states: &[SocketState]) -> io::Result<Vec<Socket>> {
+        net_filtered(&self.read("net/tcp6")?, states)
+    }
+
+    pub fn udp6_filtered(&self, states: &[SocketState]) -> io::Result<Vec<Socket>> {
+        net_filtered(&self.read("net/udp6")?, states)
+    }
+
+    /// Sorted, deduplicated local ports of listening TCP sockets, over both
+    /// IPv4 and IPv6. A missing `tcp6` (IPv6 disabled) is not an error.
+    pub fn listening_ports(&self) -> io::Result<Vec<u16>> {
+        let mut sockets = self.tcp_filtered(&[SocketState::Listen])?;
+        match self.tcp6_filtered(&[SocketState::Listen]) {
+            Ok(v6) => sockets.extend(v6),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        let mut ports = sockets
+            .iter()
+            .map(|socket| socket.local_port)
+            .collect::<Vec<u16>>();
+        ports.sort_unstable();
+        ports.dedup();
+
+        Ok(ports)
+    }
+
+    pub fn tcp_iter(&self) -> io::Result<SocketIter> {
+        SocketIter::open(self.root.join("net/tcp"))
+    }
+
+    pub fn udp_iter(&self) -> io::Result<SocketIter> {
+        SocketIter::open(self.root.join("net/udp"))
+    }
+
+    pub fn tcp6_iter(&self) -> io::Result<SocketIter> {
+        SocketIter::open(self.root.join("net/tcp6"))
+    }
+
+    pub fn udp6_iter(&self) -> io::Result<SocketIter> {
+        SocketIter::open(self.root.join("net/udp6"))
+    }
+
+    pub fn loadavg(&self) -> io::Result<LoadAvg> {
+        Ok(self.read("loadavg")?.parse()?)
+    }
+
+    pub fn uptime(&self) -> io::Result<Uptime> {
+        Ok(self.read("uptime")?.parse()?)
+    }
+
+    pub fn vmstat(&self) -> io::Result<VmStat> {
+        Ok(self.read("vmstat")?.parse()?)
+    }
+
+    pub fn diskstats(&self) -> io::Result<Vec<DiskStat>> {
+        to_vec(&self.read("diskstats")?, 0)
+    }
+
+    pub fn net_dev(&self) -> io::Result<Vec<NetDev>> {
+        to_vec(&self.read("net/dev")?, 2)
+    }
+
+    pub fn unix(&self) -> io::Result<Vec<UnixSocket>> {
+        to_vec(&self.read("net/unix")?, 1)
+    }
+
+    pub fn process_stat(&self, pid: u32) -> io::Result<ProcessStat> {
+        Ok(self.read(&format!("{}/stat", pid))?.parse()?)
+    }
+
+    /// Stat of `loadavg.last_pid`, or `None` if that process has already
+    /// exited.
+    pub fn newest_process(&self, loadavg: &LoadAvg) -> io::Result<Option<ProcessStat>> {
+        match self.process_stat(loadavg.last_pid) {
+            Ok(stat) => Ok(Some(stat)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn process_mem(&self, pid: u32) -> io::Result<ProcessMem> {
+        Ok(self.read(&format!("{}/statm", pid))?.parse()?)
+    }
+
+    /// I/O counters of `pid`. Reading another user's process usually needs
+    /// privileges, so this may fail with `PermissionDenied`.
+    pub fn process_io(&self, pid: u32) -> io::Result<ProcessIo> {
+        Ok(self.read(&format!("{}/io", pid))?.parse()?)
+    }
+
+    /// Like `process_io()`, for callers holding a signed `pid_t`. A negative
+    /// `pid` fails with `InvalidInput`.
+    pub fn pid_io(&self, pid: i32) -> io::Result<PidIo> {
+        let pid = u32::try_from(pid)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "negative pid"))?;
+        self.process_io(pid)
+    }
+
+    pub fn process_status(&self, pid: u32) -> io::Result<ProcessStatus> {
+        Ok(self.read(&format!("{}/status", pid))?.parse()?)
+    }
+
+    /// PIDs of every process holding a descriptor to the socket `inode`.
+    ///
+    /// This walks `[pid]/fd/*` for every process, which requires permission
+    /// to read other processes' descriptors (usually root, or the same user).
+    /// Processes that cannot be inspected are skipped, so only visible owners
+    /// are returned. Prefer `build_inode_pid_map()` when resolving many
+    /// sockets.
+    pub fn socket_pids(&self, inode: u64) -> io::Result<Vec<u32>> {
+        let mut pids = Vec::new();
+        self.scan_socket_fds(|pid, found| {
+            if found == inode && !pids.contains(&pid) {
+                pids.push(pid);
+            }
+        })?;
+
+        pids.sort_unstable();
+        Ok(pids)
+    }
+
+    /// Maps every visible socket inode to the PIDs holding it, in one pass.
+    ///
+    /// The same permission caveats as `socket_pids()` apply.
+    pub fn build_inode_pid_map(&self) -> io::Result<HashMap<u64, Vec<u32>>> {
+        let mut map = HashMap::<u64, Vec<u32>>::new();
+        self.scan_socket_fds(|pid, inode| {
+            let pids = map.entry(inode).or_default();
+            if !pids.contains(&pid) {
+                pids.push(pid);
+            }
+        })?;
+
+        for pids in map.values_mut() {
+            pids.sort_unstable();
+        }
+
+        Ok(map)
+    }
+
+    /// Calls `found(pid, inode)` for every socket descriptor in `[pid]/fd`.
+    fn scan_socket_fds<F: FnMut(u32, u64)>(&self, mut found: F) -> io::Result<()> {
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            let pid = match entry.file_name().to_str().map(str::parse::<u32>) {
+                Some(Ok(pid)) => pid,
+                _ => continue,
+            };
+
+            // The process may have exited, or its fds may not be readable.
+            let fds = match fs::read_dir(entry.path().join("fd")) {
+                Ok(fds) => fds,
+                Err(_) => continue,
+            };
+
+            for fd in fds {
+                let target = match fd.and_then(|fd| fs::read_link(fd.path())) {
+                    Ok(target) => target,
+                    Err(_) => continue,
+                };
+
+                if let Some(inode) = target.to_str().and_then(to_socket_inode) {
+                    found(pid, inode);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn interrupts(&self) -> io::Result<Vec<Interrupt>> {
+        Ok(to_interrupts(&self.read("interrupts")?)?)
+    }
+
+    pub fn cpuinfo(&self) -> io::Result<Vec<CpuInfo>> {
+        Ok(to_cpuinfos(&self.read("cpuinfo")?)?)
+    }
+
+    pub fn pressure_cpu(&self) -> io::Result<Pressure> {
+        Ok(self.read("pressure/cpu")?.parse()?)
+    }
+
+    pub fn pressure_memory(&self) -> io::Result<Pressure> {
+        Ok(self.read("pressure/memory")?.parse()?)
+    }
+
+    pub fn pressure_io(&self) -> io::Result<Pressure> {
+        Ok(self.read("pressure/io")?.parse()?)
+    }
+
+    pub fn swaps(&self) -> io::Result<Vec<Swap>> {
+        to_vec(&self.read("swaps")?, 1)
+    }
+
+    pub fn mounts(&self) -> io::Result<Vec<Mount>> {
+        to_vec(&self.read("mounts")?, 0)
+    }
+
+    pub fn snmp(&self) -> io::Result<Snmp> {
+        Ok(self.read("net/snmp")?.parse()?)
+    }
+
+    pub fn netstat(&self) -> io::Result<NetStat> {
+        Ok(self.read("net/netstat")?.parse()?)
+    }
+
+    pub fn route(&self) -> io::Result<Vec<Route>> {
+        to_vec(&self.read("net/route")?, 1)
+    }
+
+    pub fn buddyinfo(&self) -> io::Result<Vec<BuddyInfo>> {
+        to_vec(&self.read("buddyinfo")?, 0)
+    }
+
+    pub fn snapshot(&self) -> io::Result<Snapshot> {
+        let captured_at = Instant::now();
+
+        Ok(Snapshot {
+            stat: self.stat()?,
+            meminfo: self.meminfo()?,
+            loadavg: self.loadavg()?,
+            uptime: self.uptime()?,
+            captured_at,
+        })
+    }
+
+    /// Contents of `/proc/sys/kernel/hostname`.
+    pub fn hostname(&self) -> io::Result<String> {
+        Ok(self.read("sys/kernel/hostname")?.trim_end().to_owned())
+    }
+
+    /// Kernel release, e.g. `6.1.0-18-amd64`, from `/proc/sys/kernel/osrelease`.
+    pub fn kernel_version(&self) -> io::Result<String> {
+        Ok(self.read("sys/kernel/osrelease")?.trim_end().to_owned())
+    }
+
+    /// Contents of `/proc/sys/kernel/pid_max`.
+    pub fn pid_max(&self) -> io::Result<u32> {
+        let content = self.read("sys/kernel/pid_max")?;
+        let line = content.trim_end();
+        Ok(to_field(Some(line), "pid_max", line)?)
+    }
+
+    pub fn partitions(&self) -> io::Result<Vec<Partition>> {
+        to_vec(&self.read("partitions")?, 1)
+    }
+
+    pub fn stat_cache(&self) -> io::Result<StatCache> {
+        ProcfsCache::open(self.root.join("stat"))
+    }
+
+    pub fn meminfo_cache(&self) -> io::Result<MemInfoCache> {
+        ProcfsCache::open(self.root.join("meminfo"))
+    }
+
+    pub fn arp(&self) -> io::Result<Vec<ArpEntry>> {
+        to_vec(&self.read("net/arp")?, 1)
+    }
+
+    pub fn filesystems(&self) -> io::Result<Vec<Filesystem>> {
+        to_vec(&self.read("filesystems")?, 0)
+    }
+
+    pub fn modules(&self) -> io::Result<Vec<Module>> {
+        to_vec(&self.read("modules")?, 0)
+    }
+
+    pub fn snmp6(&self) -> io::Result<Snmp6> {
+        Ok(self.read("net/snmp6")?.parse()?)
+    }
+
+    pub fn igmp(&self) -> io::Result<Vec<IgmpEntry>> {
+        Ok(to_igmp_entries(&self.read("net/igmp")?)?)
+    }
+
+    pub fn zoneinfo(&self) -> io::Result<Vec<ZoneInfo>> {
+        Ok(to_zoneinfos(&self.read("zoneinfo")?)?)
+    }
+
+    pub fn sockstat(&self) -> io::Result<SockStat> {
+        Ok(self.read("net/sockstat")?.parse()?)
+    }
+
+    pub fn protocols(&self) -> io::Result<Vec<Protocol>> {
+        to_vec(&self.read("net/protocols")?, 1)
+    }
+
+    /// IPv6 counters of a single interface, from `net/dev_snmp6/[iface]`.
+    pub fn dev_snmp6(&self, iface: &str) -> io::Result<HashMap<String, u64>> {
+        let snmp6: Snmp6 = self.read(&format!("net/dev_snmp6/{}", iface))?.parse()?;
+        Ok(snmp6.values)
+    }
+
+    /// IPv6 counters of every interface, keyed by interface name.
+    pub fn dev_snmp6_all(&self) -> io::Result<HashMap<String, HashMap<String, u64>>> {
+        let mut all = HashMap::new();
+        for entry in fs::read_dir(self.root.join("net/dev_snmp6"))? {
+            let entry = entry?;
+            let iface = match entry.file_name().into_string() {
+                Ok(iface) => iface,
+                Err(_) => continue,
+            };
+
+            let counters = self.dev_snmp6(&iface)?;
+            all.insert(iface, counters);
+        }
+
+        Ok(all)
+    }
+
+    pub fn wireless(&self) -> io::Result<Vec<Wireless>> {
+        to_vec(&self.read("net/wireless")?, 2)
+    }
+
+    pub fn packet(&self) -> io::Result<Vec<PacketSocket>> {
+        to_vec(&self.read("net/packet")?, 1)
+    }
+
+    pub fn schedstat(&self) -> io::Result<SchedStat> {
+        Ok(self.read("schedstat")?.parse()?)
+    }
+
+    pub fn crypto(&self) -> io::Result<Vec<Crypto>> {
+        Ok(to_cryptos(&self.read("crypto")?)?)
+    }
+
+    pub fn netlink(&self) -> io::Result<Vec<NetlinkSocket>> {
+        to_vec(&self.read("net/netlink")?, 1)
+    }
+
+    pub fn ptype(&self) -> io::Result<Vec<PacketType>> {
+        to_vec(&self.read("net/ptype")?, 1)
+    }
+
+    pub fn mdstat(&self) -> io::Result<MdStat> {
+        Ok(self.read("mdstat")?.parse()?)
+    }
+
+    pub fn nfsd_stats(&self) -> io::Result<NfsdStats> {
+        Ok(self.read("net/rpc/nfsd")?.parse()?)
+    }
+
+    /// One entry per online CPU, in CPU order.
+    pub fn softnet_stat(&self) -> io::Result<Vec<SoftnetStat>> {
+        to_vec(&self.read("net/softnet_stat")?, 0)
+    }
+
+    fn read(&self, file: &str) -> io::Result<String> {
+        read_file(self.root.join(file))
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn stat() -> io::Result<Stat> {
+    ProcfsReader::default().stat()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn stat() -> io::Result<Stat> {
+    Err(unsupported())
+}
+
+pub fn stat_aggregate_only() -> io::Result<Stat> {
+    ProcfsReader::default().stat_aggregate_only()
+}
+
+pub fn cpu_usage_over(interval: Duration) -> io::Result<f64> {
+    ProcfsReader::default().cpu_usage_over(interval)
+}
+
+pub fn system_load(interval: Duration) -> io::Result<SystemLoad> {
+    ProcfsReader::default().system_load(interval)
+}
+
+pub fn cpus_usage_over(interval: Duration) -> io::Result<Vec<f64>> {
+    ProcfsReader::default().cpus_usage_over(interval)
+}
+
+pub fn watch_cpu(interval: Duration) -> (JoinHandle<io::Result<()>>, Receiver<f64>) {
+    ProcfsReader::default().watch_cpu(interval)
+}
+
+#[cfg(target_os = "linux")]
+pub fn meminfo() -> io::Result<MemInfo> {
+    ProcfsReader::default().meminfo()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn meminfo() -> io::Result<MemInfo> {
+    Err(unsupported())
+}
+
+#[cfg(target_os = "linux")]
+pub fn tcp() -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().tcp()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn tcp() -> io::Result<Vec<Socket>> {
+    Err(unsupported())
+}
+
+#[cfg(target_os = "linux")]
+pub fn udp() -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().udp()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn udp() -> io::Result<Vec<Socket>> {
+    Err(unsupported())
+}
+
+pub fn tcp6() -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().tcp6()
+}
+
+pub fn udp6() -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().udp6()
+}
+
+pub fn tcp_filtered(states: &[SocketState]) -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().tcp_filtered(states)
+}
+
+pub fn udp_filtered(states: &[SocketState]) -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().udp_filtered(states)
+}
+
+pub fn tcp6_filtered(states: &[SocketState]) -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().tcp6_filtered(states)
+}
+
+pub fn udp6_filtered(states: &[SocketState]) -> io::Result<Vec<Socket>> {
+    ProcfsReader::default().udp6_filtered(states)
+}
+
+pub fn listening_ports() -> io::Result<Vec<u16>> {
+    ProcfsReader::default().listening_ports()
+}
+
+pub fn tcp_snapshot() -> io::Result<SocketSnapshot> {
+    ProcfsReader::default().tcp_snapshot()
+}
+
+pub fn udp_snapshot() -> io::Result<SocketSnapshot> {
+    ProcfsReader::default().udp_snapshot()
+}
+
+pub fn tcp6_snapshot() -> io::Result<SocketSnapshot> {
+    ProcfsReader::default().tcp6_snapshot()
+}
+
+pub fn udp6_snapshot() -> io::Result<SocketSnapshot> {
+    ProcfsReader::default().udp6_snapshot()
+}
+
+pub fn tcp_iter() -> io::Result<SocketIter> {
+    ProcfsReader::default().tcp_iter()
+}
+
+pub fn udp_iter() -> io::Result<SocketIter> {
+    ProcfsReader::default().udp_iter()
+}
+
+pub fn tcp6_iter() -> io::Result<SocketIter> {
+    ProcfsReader::default().tcp6_iter()
+}
+
+pub fn udp6_iter() -> io::Result<SocketIter> {
+    ProcfsReader::default().udp6_iter()
+}
+
+pub fn loadavg() -> io::Result<LoadAvg> {
+    ProcfsReader::default().loadavg()
+}
+
+pub fn uptime() -> io::Result<Uptime> {
+    ProcfsReader::default().uptime()
+}
+
+pub fn vmstat() -> io::Result<VmStat> {
+    ProcfsReader::default().vmstat()
+}
+
+pub fn diskstats() -> io::Result<Vec<DiskStat>> {
+    ProcfsReader::default().diskstats()
+}
+
+pub fn net_dev() -> io::Result<Vec<NetDev>> {
+    ProcfsReader::default().net_dev()
+}
+
+pub fn unix() -> io::Result<Vec<UnixSocket>> {
+    ProcfsReader::default().unix()
+}
+
+pub fn process_stat(pid: u32) -> io::Result<ProcessStat> {
+    ProcfsReader::default().process_stat(pid)
+}
+
+pub fn process_mem(pid: u32) -> io::Result<ProcessMem> {
+    ProcfsReader::default().process_mem(pid)
+}
+
+pub fn process_io(pid: u32) -> io::Result<ProcessIo> {
+    ProcfsReader::default().process_io(pid)
+}
+
+pub fn pid_io(pid: i32) -> io::Result<PidIo> {
+    ProcfsReader::default().pid_io(pid)
+}
+
+pub fn process_status(pid: u32) -> io::Result<ProcessStatus> {
+    ProcfsReader::default().process_status(pid)
+}
+
+/// See `ProcfsReader::socket_pids()`.
+pub fn socket_pids(inode: u64) -> io::Result<Vec<u32>> {
+    ProcfsReader::default().socket_pids(inode)
+}
+
+/// See `ProcfsReader::build_inode_pid_map()`.
+pub fn build_inode_pid_map() -> io::Result<HashMap<u64, Vec<u32>>> {
+    ProcfsReader::default().build_inode_pid_map()
+}
+
+pub fn interrupts() -> io::Result<Vec<Interrupt>> {
+    ProcfsReader::default().interrupts()
+}
+
+pub fn cpuinfo() -> io::Result<Vec<CpuInfo>> {
+    ProcfsReader::default().cpuinfo()
+}
+
+pub fn pressure_cpu() -> io::Result<Pressure> {
+    ProcfsReader::default().pressure_cpu()
+}
+
+pub fn pressure_memory() -> io::Result<Pressure> {
+    ProcfsReader::default().pressure_memory()
+}
+
+pub fn pressure_io() -> io::Result<Pressure> {
+    ProcfsReader::default().pressure_io()
+}
+
+pub fn swaps() -> io::Result<Vec<Swap>> {
+    ProcfsReader::default().swaps()
+}
+
+pub fn mounts() -> io::Result<Vec<Mount>> {
+    ProcfsReader::default().mounts()
+}
+
+pub fn snmp() -> io::Result<Snmp> {
+    ProcfsReader::default().snmp()
+}
+
+pub fn netstat() -> io::Result<NetStat> {
+    ProcfsReader::default().netstat()
+}
+
+pub fn route() -> io::Result<Vec<Route>> {
+    ProcfsReader::default().route()
+}
+
+pub fn buddyinfo() -> io::Result<Vec<BuddyInfo>> {
+    ProcfsReader::default().buddyinfo()
+}
+
+/// Error returned by the default-root readers on platforms without procfs.
+#[cfg(not(target_os = "linux"))]
+fn unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "procfs is only available on Linux",
+    )
 }
 
-pub fn meminfo() -> io::Result<MemInfo> {
-    read_file("/proc/meminfo")?
-        .parse()
-        .map_err(|_| panic!("Infallible result occured"))
+pub fn snapshot() -> io::Result<Snapshot> {
+    ProcfsReader::default().snapshot()
 }
 
-pub fn tcp() -> io::Result<Vec<Socket>> {
-    net("/proc/net/tcp")
+pub fn hostname() -> io::Result<String> {
+    ProcfsReader::default().hostname()
 }
 
-pub fn udp() -> io::Result<Vec<Socket>> {
-    net("/proc/net/udp")
+pub fn kernel_version() -> io::Result<String> {
+    ProcfsReader::default().kernel_version()
+}
+
+pub fn pid_max() -> io::Result<u32> {
+    ProcfsReader::default().pid_max()
+}
+
+pub fn partitions() -> io::Result<Vec<Partition>> {
+    ProcfsReader::default().partitions()
+}
+
+pub fn stat_cache() -> io::Result<StatCache> {
+    ProcfsReader::default().stat_cache()
+}
+
+pub fn meminfo_cache() -> io::Result<MemInfoCache> {
+    ProcfsReader::default().meminfo_cache()
+}
+
+pub fn arp() -> io::Result<Vec<ArpEntry>> {
+    ProcfsReader::default().arp()
+}
+
+pub fn filesystems() -> io::Result<Vec<Filesystem>> {
+    ProcfsReader::default().filesystems()
+}
+
+/// Parses `/proc/net/{tcp,udp,tcp6,udp6}` content, header included, from any
+/// reader.
+pub fn sockets_from_reader<R: Read>(reader: R) -> io::Result<Vec<Socket>> {
+    net(&read_to_string(reader)?)
+}
+
+/// Parses a single `/proc/net/{tcp,udp,tcp6,udp6}` row, e.g. one captured
+/// from a log.
+///
+/// ```
+/// let line = "  49: 0100007F:1132 5B41EE2E:0050 01 00000000:00000000 00:00000000 00000000  1001        0 2796814 1 ffff938ed0741080";
+/// let socket = linux_stats::parse_socket(line).unwrap();
+/// assert_eq!(socket.to_string(), "127.0.0.1:4402 -> 46.238.65.91:80 (ESTABLISHED)");
+/// ```
+pub fn parse_socket(line: &str) -> Result<Socket, ParseError> {
+    line.parse()
+}
+
+fn read_to_string<R: Read>(mut reader: R) -> io::Result<String> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+pub fn modules() -> io::Result<Vec<Module>> {
+    ProcfsReader::default().modules()
+}
+
+pub fn snmp6() -> io::Result<Snmp6> {
+    ProcfsReader::default().snmp6()
+}
+
+pub fn igmp() -> io::Result<Vec<IgmpEntry>> {
+    ProcfsReader::default().igmp()
+}
+
+pub fn zoneinfo() -> io::Result<Vec<ZoneInfo>> {
+    ProcfsReader::default().zoneinfo()
+}
+
+#[cfg(feature = "users")]
+fn username_for(uid: u32) -> io::Result<Option<String>> {
+    use std::ffi::CStr;
+
+    let mut buf = vec![0 as libc::c_char; 1024];
+    loop {
+        // SAFETY: every pointer refers to a live local, and `buf.len()` is
+        // the real size of the scratch buffer.
+        let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        let rc =
+            unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+
+        match rc {
+            0 if result.is_null() => return Ok(None),
+            // SAFETY: on success `pw_name` points into `buf`, NUL-terminated.
+            0 => {
+                let name = unsafe { CStr::from_ptr(pwd.pw_name) };
+                return Ok(Some(name.to_string_lossy().into_owned()));
+            }
+            libc::ERANGE => buf.resize(buf.len() * 2, 0),
+            _ => return Err(io::Error::from_raw_os_error(rc)),
+        }
+    }
+}
+
+#[cfg(not(feature = "users"))]
+fn username_for(uid: u32) -> io::Result<Option<String>> {
+    Ok(to_username(&read_file("/etc/passwd")?, uid))
+}
+
+/// Looks up `uid` in `passwd(5)` formatted content.
+#[cfg_attr(feature = "users", allow(dead_code))]
+fn to_username(passwd: &str, uid: u32) -> Option<String> {
+    passwd
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let mut fields = line.split(':');
+            let name = fields.next()?;
+            let entry_uid = fields.nth(1)?.parse::<u32>().ok()?;
+            if entry_uid == uid {
+                Some(name.to_owned())
+            } else {
+                None
+            }
+        })
+}
+
+pub fn sockstat() -> io::Result<SockStat> {
+    ProcfsReader::default().sockstat()
+}
+
+pub fn protocols() -> io::Result<Vec<Protocol>> {
+    ProcfsReader::default().protocols()
+}
+
+pub fn dev_snmp6(iface: &str) -> io::Result<HashMap<String, u64>> {
+    ProcfsReader::default().dev_snmp6(iface)
+}
+
+pub fn dev_snmp6_all() -> io::Result<HashMap<String, HashMap<String, u64>>> {
+    ProcfsReader::default().dev_snmp6_all()
+}
+
+pub fn wireless() -> io::Result<Vec<Wireless>> {
+    ProcfsReader::default().wireless()
+}
+
+pub fn packet() -> io::Result<Vec<PacketSocket>> {
+    ProcfsReader::default().packet()
+}
+
+pub fn schedstat() -> io::Result<SchedStat> {
+    ProcfsReader::default().schedstat()
+}
+
+pub fn crypto() -> io::Result<Vec<Crypto>> {
+    ProcfsReader::default().crypto()
+}
+
+pub fn netlink() -> io::Result<Vec<NetlinkSocket>> {
+    ProcfsReader::default().netlink()
 }
 
-fn read_file(path: &str) -> io::Result<String> {
+pub fn ptype() -> io::Result<Vec<PacketType>> {
+    ProcfsReader::default().ptype()
+}
+
+pub fn mdstat() -> io::Result<MdStat> {
+    ProcfsReader::default().mdstat()
+}
+
+pub fn nfsd_stats() -> io::Result<NfsdStats> {
+    ProcfsReader::default().nfsd_stats()
+}
+
+pub fn softnet_stat() -> io::Result<Vec<SoftnetStat>> {
+    ProcfsReader::default().softnet_stat()
+}
+
+fn read_file<P: AsRef<Path>>(path: P) -> io::Result<String> {
     let file = File::open(path);
     let mut content = String::new();
 
@@ -412,100 +4308,656 @@ fn read_file(path: &str) -> io::Result<String> {
         .and(Ok(content))
 }
 
-fn net(file: &str) -> io::Result<Vec<Socket>> {
-    let content = read_file(file);
-    match content {
-        Ok(c) => Ok(c.lines().skip(1).map(to_net_socket).collect()),
-        Err(e) => Err(e),
+fn net(content: &str) -> io::Result<Vec<Socket>> {
+    content
+        .lines()
+        .skip(1)
+        .map(|line| to_net_socket(line).map_err(io::Error::from))
+        .collect()
+}
+
+/// Parses one `T` per non-blank line, after skipping `header` lines.
+fn to_vec<T: FromStr<Err = ParseError>>(content: &str, header: usize) -> io::Result<Vec<T>> {
+    content
+        .lines()
+        .skip(header)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.parse::<T>().map_err(io::Error::from))
+        .collect()
+}
+
+/// Like `to_vecu64`, but reads unparseable values as 0 when `opts` is
+/// lenient, so every value stays in its column.
+fn to_vecu64_with(line: &str, opts: ParseOptions) -> Result<Vec<u64>, ParseError> {
+    if opts.strict {
+        return to_vecu64(line);
     }
+
+    Ok(line
+        .split_whitespace()
+        .skip(1)
+        .map(|chunk| chunk.parse().unwrap_or(0))
+        .collect())
 }
 
-fn to_vecu64(line: &str) -> Vec<u64> {
+fn to_vecu64(line: &str) -> Result<Vec<u64>, ParseError> {
     let mut chunks = line.split_whitespace();
-    let mut buf = Vec::<u64>::new();
 
     // First chunk is always a non-number, descriptive text.
     chunks.next();
 
-    for chunk in chunks {
-        buf.push(chunk.parse::<u64>().unwrap());
-    }
+    chunks
+        .map(|chunk| to_field(Some(chunk), "value", line))
+        .collect()
+}
+
+/// Parses the value of a `label value` line.
+fn to_scalar<T: FromStr>(line: &str, field: &'static str) -> Result<T, ParseError> {
+    let mut chunks = line.split_whitespace();
+    chunks.next();
 
-    buf
+    to_field(chunks.next(), field, line)
 }
 
-fn to_u64(line: &str) -> u64 {
+fn to_in_out(line: &str) -> Result<(u64, u64), ParseError> {
     let mut chunks = line.split_whitespace();
     chunks.next();
 
-    chunks.next().unwrap().parse::<u64>().unwrap()
+    Ok((
+        to_field(chunks.next(), "in", line)?,
+        to_field(chunks.next(), "out", line)?,
+    ))
+}
+
+fn to_field<T: FromStr>(
+    chunk: Option<&str>,
+    field: &'static str,
+    line: &str,
+) -> Result<T, ParseError> {
+    chunk
+        .ok_or_else(|| ParseError::missing(field, line))?
+        .parse::<T>()
+        .map_err(|_| ParseError::invalid(field, line))
+}
+
+fn to_opt_field<T: FromStr>(
+    chunk: Option<&str>,
+    field: &'static str,
+    line: &str,
+) -> Result<Option<T>, ParseError> {
+    chunk
+        .map(|chunk| {
+            chunk
+                .parse::<T>()
+                .map_err(|_| ParseError::invalid(field, line))
+        })
+        .transpose()
+}
+
+/// Finds the value of a `key=value` chunk in a whitespace-separated line.
+fn to_keyed<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    line.split_whitespace()
+        .find_map(|chunk| chunk.strip_prefix(key)?.strip_prefix('='))
+}
+
+/// Decodes the `\ooo` octal escapes procfs uses for whitespace in paths.
+fn unescape_octal(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let octal = |digits: &&[u8]| digits.iter().all(|d| (b'0'..=b'7').contains(d));
+
+        match bytes.get(i + 1..i + 4).filter(octal) {
+            Some(digits) if bytes[i] == b'\\' => {
+                let value = digits
+                    .iter()
+                    .fold(0u32, |acc, d| acc * 8 + u32::from(d - b'0'));
+                out.push(value as u8);
+                i += 4;
+            }
+            _ => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Like `net`, but only fully parses lines whose `st` column is in `states`.
+/// Lines with an undecodable state are kept so the parse error surfaces.
+fn net_filtered(content: &str, states: &[SocketState]) -> io::Result<Vec<Socket>> {
+    content
+        .lines()
+        .skip(1)
+        .filter(|line| {
+            line.split_whitespace()
+                .nth(3)
+                .and_then(to_socket_state)
+                .is_none_or(|state| states.contains(&state))
+        })
+        .map(|line| to_net_socket(line).map_err(io::Error::from))
+        .collect()
+}
+
+fn to_socket_state(st: &str) -> Option<SocketState> {
+    let value = u8::from_str_radix(st, 16).ok()?;
+    SocketState::try_from(value).ok()
 }
 
-fn to_net_socket(line: &str) -> Socket {
+fn to_net_socket(line: &str) -> Result<Socket, ParseError> {
     let mut chunks = line.split_whitespace();
-    let sl = chunks
-        .next()
-        .unwrap()
+    let mut next = |field| {
+        chunks
+            .next()
+            .ok_or_else(|| ParseError::missing(field, line))
+    };
+    let invalid = |field| ParseError::invalid(field, line);
+
+    let sl = next("sl")?
         .split(':')
         .next()
-        .unwrap()
-        .parse::<u64>()
-        .unwrap();
+        .and_then(|sl| sl.parse::<u64>().ok())
+        .ok_or_else(|| invalid("sl"))?;
 
     // Both local and remote addresses are formatted as <host>:<port> pair, so
     // split them further.
-    let local: Vec<&str> = chunks.next().unwrap().split(':').collect();
-    let remote: Vec<&str> = chunks.next().unwrap().split(':').collect();
-    let state = Vec::<u8>::from_hex(chunks.next().unwrap()).unwrap()[0];
-    let queues: Vec<&str> = chunks.next().unwrap().split(':').collect();
-    let timer: Vec<&str> = chunks.next().unwrap().split(':').collect();
-    // retrnsmt - unused
-    chunks.next().unwrap();
-    let uid = chunks.next().unwrap().parse::<u32>().unwrap();
-    // timeout - unused
-    chunks.next().unwrap();
-    let inode = chunks.next().unwrap().parse::<u64>().unwrap();
-
-    Socket {
+    let local = to_pair(next("local_address")?).ok_or_else(|| invalid("local_address"))?;
+    let remote = to_pair(next("rem_address")?).ok_or_else(|| invalid("rem_address"))?;
+    let state = to_socket_state(next("st")?).ok_or_else(|| invalid("st"))?;
+    let queues = to_pair(next("tx_queue:rx_queue")?).ok_or_else(|| invalid("tx_queue:rx_queue"))?;
+    let timer = to_pair(next("tr:tm->when")?).ok_or_else(|| invalid("tr:tm->when"))?;
+    let retransmits =
+        u64::from_str_radix(next("retrnsmt")?, 16).map_err(|_| invalid("retrnsmt"))?;
+    let uid = next("uid")?.parse::<u32>().map_err(|_| invalid("uid"))?;
+    let timeout = next("timeout")?
+        .parse::<u64>()
+        .map_err(|_| invalid("timeout"))?;
+    let inode = next("inode")?
+        .parse::<u64>()
+        .map_err(|_| invalid("inode"))?;
+    let ref_count = next("ref")?.parse::<u32>().map_err(|_| invalid("ref"))?;
+
+    // TCP lines end with `<sk> <rto> <ato> <qack> <cwnd> <ssthresh>`, while
+    // UDP lines and TCP TIME_WAIT / SYN_RECV request sockets have fewer
+    // columns.
+    let tail = chunks.skip(1).collect::<Vec<&str>>();
+    let (cwnd, ssthresh) = if tail.len() >= 5 {
+        let cwnd = tail[3].parse::<u32>().map_err(|_| invalid("cwnd"))?;
+        let ssthresh = tail[4].parse::<i32>().map_err(|_| invalid("ssthresh"))?;
+        (Some(cwnd), Some(ssthresh))
+    } else {
+        (None, None)
+    };
+
+    let local_address = parse_proc_address(local.0).map_err(|_| invalid("local_address"))?;
+    // An all-zero remote host is occasionally printed without full padding;
+    // it is the wildcard address of the local address's family.
+    let remote_address = parse_proc_address(remote.0)
+        .ok()
+        .or_else(|| to_unspecified(remote.0, &local_address))
+        .ok_or_else(|| invalid("rem_address"))?;
+
+    Ok(Socket {
         sl,
-        local_address: to_ipaddr(local[0]),
-        local_port: u16::from_str_radix(local[1], 16).unwrap(),
-        remote_address: to_ipaddr(remote[0]),
-        remote_port: u16::from_str_radix(remote[1], 16).unwrap(),
-        state: SocketState::from_u8(state).unwrap(),
-        tx_queue: u64::from_str_radix(queues[0], 16).unwrap(),
-        rx_queue: u64::from_str_radix(queues[1], 16).unwrap(),
-        timer: match timer[0].parse::<u8>().unwrap() {
-            0 => SocketTimerState::Inactive,
-            _ => SocketTimerState::Active(u64::from_str_radix(timer[1], 16).unwrap()),
-        },
+        local_address,
+        local_port: to_port(local.1).ok_or_else(|| invalid("local_address"))?,
+        remote_address,
+        remote_port: to_port(remote.1).ok_or_else(|| invalid("rem_address"))?,
+        state,
+        tx_queue: u64::from_str_radix(queues.0, 16).map_err(|_| invalid("tx_queue"))?,
+        rx_queue: u64::from_str_radix(queues.1, 16).map_err(|_| invalid("rx_queue"))?,
+        timer: to_timer(timer).ok_or_else(|| invalid("tr:tm->when"))?,
+        retransmits,
         uid,
+        timeout,
         inode,
+        ref_count,
+        cwnd,
+        ssthresh,
+    })
+}
+
+/// Parses a hex port of any width, e.g. `0050`, `50` or `00000050`.
+fn to_port(hex: &str) -> Option<u16> {
+    if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u16::from_str_radix(hex, 16).ok()
+}
+
+/// Unspecified address in the family of `like`, if `hex` is all zeros.
+fn to_unspecified(hex: &str, like: &IpAddr) -> Option<IpAddr> {
+    if hex.is_empty() || !hex.bytes().all(|b| b == b'0') {
+        return None;
+    }
+
+    Some(match like {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+    })
+}
+
+fn to_timer((kind, when): (&str, &str)) -> Option<SocketTimerState> {
+    let when = clock_ticks_to_duration(u64::from_str_radix(when, 16).ok()?, USER_HZ)?;
+
+    match kind.parse::<u8>().ok()? {
+        0 => Some(SocketTimerState::Inactive),
+        1 => Some(SocketTimerState::Retransmit(when)),
+        2 => Some(SocketTimerState::Keepalive(when)),
+        3 => Some(SocketTimerState::TimeWait(when)),
+        4 => Some(SocketTimerState::Probe(when)),
+        _ => None,
+    }
+}
+
+/// Converts clock ticks at `hz` ticks per second into a `Duration`, or `None`
+/// if the count is too large to express in milliseconds.
+///
+/// Socket timers are always in `USER_HZ`; CPU times use `clk_tck()`.
+fn clock_ticks_to_duration(ticks: u64, hz: u64) -> Option<Duration> {
+    ticks
+        .checked_mul(1000)
+        .map(|millis| Duration::from_millis(millis / hz))
+}
+
+/// Extracts the inode from an fd link target of the form `socket:[inode]`.
+fn to_socket_inode(target: &str) -> Option<u64> {
+    target
+        .strip_prefix("socket:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Splits a `<a>:<b>` chunk, trimming stray whitespace from both halves.
+fn to_pair(chunk: &str) -> Option<(&str, &str)> {
+    let mut parts = chunk.split(':').map(str::trim);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(a), Some(b), None) if !a.is_empty() && !b.is_empty() => Some((a, b)),
+        _ => None,
     }
 }
 
-fn to_ipaddr(hex: &str) -> Ipv4Addr {
-    let bytes = Vec::<u8>::from_hex(hex).unwrap();
-    Ipv4Addr::from([bytes[3], bytes[2], bytes[1], bytes[0]])
+/// Decodes an address as printed in the procfs network tables: 8 hex digits
+/// for IPv4 and 32 for IPv6, each 32-bit word in host byte order.
+pub fn parse_proc_address(hex: &str) -> io::Result<IpAddr> {
+    let addr = match hex.len() {
+        8 => to_ipv4addr(hex).map(IpAddr::V4),
+        32 => to_ipv6addr(hex).map(IpAddr::V6),
+        _ => None,
+    };
+
+    Ok(addr.ok_or_else(|| ParseError::invalid("address", hex))?)
+}
+
+fn to_ipv4addr(hex: &str) -> Option<Ipv4Addr> {
+    let bytes = <[u8; 4]>::from_hex(hex).ok()?;
+    Some(Ipv4Addr::from([bytes[3], bytes[2], bytes[1], bytes[0]]))
+}
+
+fn to_ipv6addr(hex: &str) -> Option<Ipv6Addr> {
+    // The kernel prints the address as four 32-bit words, each in host
+    // (little-endian) byte order, so every 4-byte group is reversed.
+    let bytes = Vec::<u8>::from_hex(hex).ok()?;
+    let mut octets = [0u8; 16];
+    for (word, chunk) in bytes.chunks(4).enumerate() {
+        for (i, byte) in chunk.iter().rev().enumerate() {
+            octets[word * 4 + i] = *byte;
+        }
+    }
+
+    Some(Ipv6Addr::from(octets))
+}
+
+/// Non-blocking readers, enabled with the `async` feature.
+///
+/// Files are read through `tokio::fs`; parsing is the same synchronous
+/// `FromStr` code used by `ProcfsReader`.
+#[cfg(feature = "async")]
+pub mod asynchronous {
+    use super::{net, to_vec, DiskStat, LoadAvg, MemInfo, NetDev, Socket, Stat, Uptime, VmStat};
+
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    /// Asynchronous counterpart of `ProcfsReader`, reading from a
+    /// configurable procfs root.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct AsyncProcfsReader {
+        root: PathBuf,
+    }
+
+    impl Default for AsyncProcfsReader {
+        fn default() -> AsyncProcfsReader {
+            AsyncProcfsReader::from_root("/proc")
+        }
+    }
+
+    impl AsyncProcfsReader {
+        pub fn from_root<P: AsRef<Path>>(root: P) -> AsyncProcfsReader {
+            AsyncProcfsReader {
+                root: root.as_ref().to_path_buf(),
+            }
+        }
+
+        pub fn root(&self) -> &Path {
+            &self.root
+        }
+
+        pub async fn stat(&self) -> io::Result<Stat> {
+            Ok(self.read("stat").await?.parse()?)
+        }
+
+        pub async fn meminfo(&self) -> io::Result<MemInfo> {
+            Ok(self.read("meminfo").await?.parse()?)
+        }
+
+        pub async fn tcp(&self) -> io::Result<Vec<Socket>> {
+            net(&self.read("net/tcp").await?)
+        }
+
+        pub async fn udp(&self) -> io::Result<Vec<Socket>> {
+            net(&self.read("net/udp").await?)
+        }
+
+        pub async fn tcp6(&self) -> io::Result<Vec<Socket>> {
+            net(&self.read("net/tcp6").await?)
+        }
+
+        pub async fn udp6(&self) -> io::Result<Vec<Socket>> {
+            net(&self.read("net/udp6").await?)
+        }
+
+        pub async fn loadavg(&self) -> io::Result<LoadAvg> {
+            Ok(self.read("loadavg").await?.parse()?)
+        }
+
+        pub async fn uptime(&self) -> io::Result<Uptime> {
+            Ok(self.read("uptime").await?.parse()?)
+        }
+
+        pub async fn vmstat(&self) -> io::Result<VmStat> {
+            Ok(self.read("vmstat").await?.parse()?)
+        }
+
+        pub async fn diskstats(&self) -> io::Result<Vec<DiskStat>> {
+            to_vec(&self.read("diskstats").await?, 0)
+        }
+
+        pub async fn net_dev(&self) -> io::Result<Vec<NetDev>> {
+            to_vec(&self.read("net/dev").await?, 2)
+        }
+
+        async fn read(&self, file: &str) -> io::Result<String> {
+            tokio::fs::read_to_string(self.root.join(file)).await
+        }
+    }
+
+    pub async fn stat() -> io::Result<Stat> {
+        AsyncProcfsReader::default().stat().await
+    }
+
+    pub async fn meminfo() -> io::Result<MemInfo> {
+        AsyncProcfsReader::default().meminfo().await
+    }
+
+    pub async fn tcp() -> io::Result<Vec<Socket>> {
+        AsyncProcfsReader::default().tcp().await
+    }
+
+    pub async fn udp() -> io::Result<Vec<Socket>> {
+        AsyncProcfsReader::default().udp().await
+    }
+
+    pub async fn tcp6() -> io::Result<Vec<Socket>> {
+        AsyncProcfsReader::default().tcp6().await
+    }
+
+    pub async fn udp6() -> io::Result<Vec<Socket>> {
+        AsyncProcfsReader::default().udp6().await
+    }
+
+    pub async fn loadavg() -> io::Result<LoadAvg> {
+        AsyncProcfsReader::default().loadavg().await
+    }
+
+    pub async fn uptime() -> io::Result<Uptime> {
+        AsyncProcfsReader::default().uptime().await
+    }
+
+    pub async fn vmstat() -> io::Result<VmStat> {
+        AsyncProcfsReader::default().vmstat().await
+    }
+
+    pub async fn diskstats() -> io::Result<Vec<DiskStat>> {
+        AsyncProcfsReader::default().diskstats().await
+    }
+
+    pub async fn net_dev() -> io::Result<Vec<NetDev>> {
+        AsyncProcfsReader::default().net_dev().await
+    }
 }
 
 #[test]
-fn test_to_ipaddr() {
-    let addr = to_ipaddr("0100007F");
-    assert_eq!(addr.octets(), [127, 0, 0, 1]);
+fn test_parse_proc_address() {
+    let addr = parse_proc_address("0100007F").unwrap();
+    assert_eq!(addr, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+}
+
+#[test]
+fn test_parse_proc_address_v6() {
+    let addr = parse_proc_address("00000000000000000000000001000000").unwrap();
+    assert_eq!(addr, IpAddr::V6(Ipv6Addr::LOCALHOST));
+
+    let addr = parse_proc_address("B80D0120000000000000000001000000").unwrap();
+    assert_eq!(addr, "2001:db8::1".parse::<IpAddr>().unwrap());
 }
 
 #[test]
 fn test_to_net_socket() {
-    let sock = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1");
-    assert_eq!(sock.local_address.octets(), [127, 0, 0, 1]);
+    let sock = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1").unwrap();
+    assert_eq!(sock.local_address, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
     assert_eq!(sock.local_port, 4402);
-    assert_eq!(sock.remote_address.octets(), [46, 238, 65, 91]);
+    assert_eq!(
+        sock.remote_address,
+        IpAddr::V4(Ipv4Addr::new(46, 238, 65, 91))
+    );
     assert_eq!(sock.remote_port, 80);
     assert_eq!(sock.state, SocketState::Listen);
     assert_eq!(sock.tx_queue, 0xA);
     assert_eq!(sock.rx_queue, 2);
-    assert_eq!(sock.timer, SocketTimerState::Active(0xB));
+    assert_eq!(
+        sock.timer,
+        SocketTimerState::Retransmit(Duration::from_millis(110))
+    );
+    assert_eq!(sock.retransmits, 0);
     assert_eq!(sock.uid, 1001);
     assert_eq!(sock.inode, 2796814);
 }
+
+#[test]
+fn test_to_net_socket_v6() {
+    let sock = to_net_socket("   0: 00000000000000000000000001000000:0277 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21355 1 ffff8f8a5b1c0000 100 0 0 10 0").unwrap();
+    assert_eq!(sock.local_address, IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert_eq!(sock.local_port, 631);
+    assert_eq!(sock.remote_address, IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+    assert_eq!(sock.remote_port, 0);
+    assert_eq!(sock.state, SocketState::Listen);
+    assert_eq!(sock.timer, SocketTimerState::Inactive);
+    assert_eq!(sock.uid, 0);
+    assert_eq!(sock.inode, 21355);
+}
+
+#[test]
+fn test_to_net_socket_truncated() {
+    let err = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002").unwrap_err();
+    assert_eq!(err, ParseError::missing("tr:tm->when", err.line()));
+
+    let err = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 ZZ 0000000A:00000002 01:0000000B 00000000  1001        0 2796814").unwrap_err();
+    assert_eq!(err.field(), "st");
+}
+
+#[test]
+fn test_socket_display() {
+    let sock = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1").unwrap();
+    assert_eq!(
+        sock.to_string(),
+        "127.0.0.1:4402 -> 46.238.65.91:80 (LISTEN)"
+    );
+
+    let sock = to_net_socket("   0: 00000000000000000000000001000000:0277 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21355 1 ffff8f8a5b1c0000 100 0 0 10 0").unwrap();
+    assert_eq!(sock.to_string(), "[::1]:631 -> [::]:0 (LISTEN)");
+}
+
+#[test]
+fn test_socket_state_display() {
+    assert_eq!(SocketState::Established.to_string(), "ESTABLISHED");
+    assert_eq!(SocketState::TimeWait.to_string(), "TIME_WAIT");
+    assert_eq!(SocketState::FinWait1.to_string(), "FIN_WAIT1");
+}
+
+#[test]
+fn test_to_timer() {
+    assert_eq!(
+        to_timer(("00", "00000000")),
+        Some(SocketTimerState::Inactive)
+    );
+    assert_eq!(
+        to_timer(("02", "00002710")),
+        Some(SocketTimerState::Keepalive(Duration::from_secs(100)))
+    );
+    assert_eq!(
+        to_timer(("03", "00000DAC")),
+        Some(SocketTimerState::TimeWait(Duration::from_millis(35_000)))
+    );
+    assert_eq!(
+        to_timer(("04", "00000032")),
+        Some(SocketTimerState::Probe(Duration::from_millis(500)))
+    );
+    assert_eq!(to_timer(("09", "00000000")), None);
+}
+
+#[test]
+fn test_to_timer_overflow() {
+    assert_eq!(to_timer(("01", "FFFFFFFFFFFFFFFF")), None);
+
+    let err = to_net_socket("   3: 0100007F:9C4A 0100007F:1F90 01 00000001:00000000 01:FFFFFFFFFFFFFFFF 0000000A  1000        3 57264 2 ffff8f8a5a1a1100 640 40 0 1 7").unwrap_err();
+    assert_eq!(err.field(), "tr:tm->when");
+}
+
+#[test]
+fn test_to_net_socket_counters() {
+    let sock = to_net_socket("   3: 0100007F:9C4A 0100007F:1F90 01 00000001:00000000 01:00000014 0000000A  1000        3 57264 2 ffff8f8a5a1a1100 640 40 0 1 7").unwrap();
+    assert_eq!(sock.retransmits, 10);
+    assert_eq!(sock.timeout, 3);
+    assert_eq!(
+        sock.timer,
+        SocketTimerState::Retransmit(Duration::from_millis(200))
+    );
+}
+
+#[test]
+fn test_to_net_socket_extended() {
+    let sock = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1").unwrap();
+    assert_eq!(sock.ref_count, 1);
+    assert_eq!(sock.cwnd, Some(10));
+    assert_eq!(sock.ssthresh, Some(-1));
+
+    let sock = to_net_socket(" 1043: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 19788 2 ffff8f8a58a3c000 0").unwrap();
+    assert_eq!(sock.ref_count, 2);
+    assert_eq!(sock.cwnd, None);
+    assert_eq!(sock.ssthresh, None);
+
+    let err = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 x -1").unwrap_err();
+    assert_eq!(err.field(), "cwnd");
+}
+
+#[test]
+fn test_to_net_socket_large_values() {
+    let sock = to_net_socket("70000: 0100007F:1132 5B41EE2E:0050 01 FFFFFFFF:FFFFFFFF 00:00000000 00000000  1001        0 2796814 1 ffff938ed0741080 0").unwrap();
+    assert_eq!(sock.sl, 70000);
+    assert_eq!(sock.tx_queue, 0xFFFFFFFF);
+    assert_eq!(sock.rx_queue, 0xFFFFFFFF);
+
+    let sock = to_net_socket("   1: 0100007F:1132 5B41EE2E:0050 01 00000000000000FF:0A 00:00000000 00000000  1001        0 2796814 1 ffff938ed0741080 0").unwrap();
+    assert_eq!(sock.tx_queue, 255);
+    assert_eq!(sock.rx_queue, 10);
+
+    assert_eq!(
+        to_pair(" 0000000A :00000002"),
+        Some(("0000000A", "00000002"))
+    );
+    assert_eq!(to_pair("0000000A:"), None);
+    assert_eq!(to_pair(":00000002"), None);
+}
+
+#[test]
+fn test_to_net_socket_short_hex() {
+    let sock = to_net_socket("   0: 00000000:16 0:0 0A 00000000:00000000 00:00000000 00000000     0        0 21354 1 ffff8f8a5b1c0000 100 0 0 10 0").unwrap();
+    assert_eq!(sock.local_port, 22);
+    assert_eq!(sock.remote_address, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    assert_eq!(sock.remote_port, 0);
+    assert!(sock.is_wildcard());
+
+    let sock = to_net_socket("   0: 00000000000000000000000001000000:0277 0:0 0A 00000000:00000000 00:00000000 00000000     0        0 21355 1 ffff8f8a5b1c0000 100 0 0 10 0").unwrap();
+    assert_eq!(sock.remote_address, IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+    assert!(sock.is_wildcard());
+
+    let sock = to_net_socket("  49: 0100007F:1132 5B41EE2E:0050 01 00000000:00000000 00:00000000 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1").unwrap();
+    assert!(!sock.is_wildcard());
+
+    assert_eq!(to_port("+50"), None);
+    assert_eq!(to_port(""), None);
+    assert_eq!(to_port("00000050"), Some(80));
+    assert_eq!(to_unspecified("7F", &IpAddr::V4(Ipv4Addr::LOCALHOST)), None);
+}
+
+#[test]
+fn test_to_socket_inode() {
+    assert_eq!(to_socket_inode("socket:[2796814]"), Some(2796814));
+    assert_eq!(to_socket_inode("pipe:[2796814]"), None);
+    assert_eq!(to_socket_inode("/dev/null"), None);
+    assert_eq!(to_socket_inode("socket:[]"), None);
+}
+
+#[test]
+fn test_unescape_octal() {
+    assert_eq!(unescape_octal("/dev/sda2"), "/dev/sda2");
+    assert_eq!(unescape_octal("/swap\\040file"), "/swap file");
+    assert_eq!(unescape_octal("/a\\011b\\134c"), "/a\tb\\c");
+    assert_eq!(unescape_octal("/trailing\\04"), "/trailing\\04");
+    assert_eq!(unescape_octal("/not\\999octal"), "/not\\999octal");
+}
+
+#[test]
+fn test_to_igmp_entries_malformed() {
+    let header = "Idx\tDevice    : Count Querier\tGroup    Users Timer\tReporter\n";
+
+    let orphan = format!("{}\t\t\t\t010000E0     1 0:00000000\t\t0\n", header);
+    assert_eq!(to_igmp_entries(&orphan).unwrap_err().field(), "Device");
+
+    let reporter = format!(
+        "{}1\tlo        :     1      V3\n\t\t\t\t010000E0     1 0:00000000\t\t2\n",
+        header
+    );
+    assert_eq!(to_igmp_entries(&reporter).unwrap_err().field(), "Reporter");
+}
+
+#[test]
+fn test_to_username() {
+    let passwd = "# comment
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+broken
+nathan:x:1000:1000:Nathan,,,:/home/nathan:/bin/bash
+";
+
+    assert_eq!(to_username(passwd, 0), Some("root".to_owned()));
+    assert_eq!(to_username(passwd, 1000), Some("nathan".to_owned()));
+    assert_eq!(to_username(passwd, 4242), None);
+}
@@ -0,0 +1,26 @@
+extern crate linux_stats;
+
+use linux_stats::parse_proc_address;
+
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn proc_address() {
+    assert_eq!(
+        parse_proc_address("0100007F").unwrap(),
+        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    );
+    assert_eq!(
+        parse_proc_address("00000000000000000000000001000000").unwrap(),
+        IpAddr::V6(Ipv6Addr::LOCALHOST)
+    );
+}
+
+#[test]
+fn proc_address_invalid() {
+    for hex in &["", "0100007", "0100007G", "000000000000000000000000010000"] {
+        let err = parse_proc_address(hex).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
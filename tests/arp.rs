@@ -0,0 +1,37 @@
+extern crate linux_stats;
+
+use linux_stats::{ArpEntry, ProcfsReader};
+
+use std::net::Ipv4Addr;
+
+#[test]
+fn arp() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let entries = reader.arp().unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(
+        entries[0],
+        ArpEntry {
+            ip_address: Ipv4Addr::new(192, 168, 2, 1),
+            hw_type: 0x1,
+            flags: 0x2,
+            hw_address: "a4:91:b1:0e:22:7c".to_owned(),
+            mask: "*".to_owned(),
+            device: "eth0".to_owned(),
+        }
+    );
+    assert_eq!(entries[1].flags, 0x0);
+    assert_eq!(entries[2].device, "docker0");
+}
+
+#[test]
+fn arp_malformed() {
+    let err = "192.168.2 0x1 0x2 a4:91:b1:0e:22:7c * eth0"
+        .parse::<ArpEntry>()
+        .unwrap_err();
+    assert_eq!(err.field(), "IP address");
+
+    let err = "192.168.2.1 0x1 0x2".parse::<ArpEntry>().unwrap_err();
+    assert_eq!(err.field(), "HW address");
+}
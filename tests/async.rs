@@ -0,0 +1,40 @@
+#![cfg(feature = "async")]
+
+extern crate linux_stats;
+
+use linux_stats::asynchronous::AsyncProcfsReader;
+use linux_stats::ProcfsReader;
+
+fn roots() -> (AsyncProcfsReader, ProcfsReader) {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc");
+    (
+        AsyncProcfsReader::from_root(root),
+        ProcfsReader::from_root(root),
+    )
+}
+
+#[tokio::test]
+async fn async_matches_blocking() {
+    let (nonblocking, blocking) = roots();
+
+    assert_eq!(nonblocking.stat().await.unwrap(), blocking.stat().unwrap());
+    assert_eq!(
+        nonblocking.meminfo().await.unwrap(),
+        blocking.meminfo().unwrap()
+    );
+    assert_eq!(
+        nonblocking.loadavg().await.unwrap(),
+        blocking.loadavg().unwrap()
+    );
+    assert_eq!(
+        nonblocking.diskstats().await.unwrap(),
+        blocking.diskstats().unwrap()
+    );
+    assert_eq!(nonblocking.tcp().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn async_missing_file() {
+    let reader = AsyncProcfsReader::from_root("/nonexistent");
+    assert!(reader.stat().await.is_err());
+}
@@ -0,0 +1,40 @@
+extern crate linux_stats;
+
+use linux_stats::{BuddyInfo, ProcfsReader};
+
+#[test]
+fn buddyinfo_line() {
+    let info = "Node 0, zone   Normal     10      5      3      0      1"
+        .parse::<BuddyInfo>()
+        .unwrap();
+
+    assert_eq!(
+        info,
+        BuddyInfo {
+            node: 0,
+            zone: "Normal".to_owned(),
+            free_counts: vec![10, 5, 3, 0, 1],
+        }
+    );
+}
+
+#[test]
+fn buddyinfo_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let zones = reader.buddyinfo().unwrap();
+
+    assert_eq!(zones.len(), 3);
+    assert_eq!(zones[0].zone, "DMA");
+    assert_eq!(zones[1].zone, "DMA32");
+    assert_eq!(zones[1].free_counts.len(), 11);
+    assert_eq!(zones[1].free_counts[10], 311);
+    assert_eq!(zones[2].node, 0);
+    assert_eq!(zones[2].free_counts[0], 1032);
+}
+
+#[test]
+fn buddyinfo_malformed() {
+    assert!("zone Normal 1 2 3".parse::<BuddyInfo>().is_err());
+    assert!("Node x, zone Normal 1 2 3".parse::<BuddyInfo>().is_err());
+    assert!("Node 0, zone Normal 1 two 3".parse::<BuddyInfo>().is_err());
+}
@@ -0,0 +1,85 @@
+extern crate linux_stats;
+
+use linux_stats::{LoadAvg, ProcfsReader};
+
+use std::io;
+
+#[test]
+fn bundle_every_reader() {
+    let reader = ProcfsReader::from_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+
+    reader.stat().unwrap();
+    reader.stat_aggregate_only().unwrap();
+    reader.meminfo().unwrap();
+    reader.loadavg().unwrap();
+    reader.uptime().unwrap();
+    reader.vmstat().unwrap();
+    reader.snapshot().unwrap();
+    reader.cpuinfo().unwrap();
+    reader.interrupts().unwrap();
+    reader.pressure_cpu().unwrap();
+    reader.pressure_memory().unwrap();
+    reader.pressure_io().unwrap();
+    reader.schedstat().unwrap();
+    reader.buddyinfo().unwrap();
+    reader.zoneinfo().unwrap();
+    reader.crypto().unwrap();
+    reader.modules().unwrap();
+    reader.filesystems().unwrap();
+
+    reader.diskstats().unwrap();
+    reader.partitions().unwrap();
+    reader.swaps().unwrap();
+    reader.mounts().unwrap();
+    reader.mdstat().unwrap();
+
+    reader.tcp().unwrap();
+    reader.udp().unwrap();
+    reader.tcp6().unwrap();
+    reader.udp6().unwrap();
+    reader.unix().unwrap();
+    reader.listening_ports().unwrap();
+    reader.net_dev().unwrap();
+    reader.snmp().unwrap();
+    reader.snmp6().unwrap();
+    reader.netstat().unwrap();
+    reader.sockstat().unwrap();
+    reader.route().unwrap();
+    reader.arp().unwrap();
+    reader.igmp().unwrap();
+    reader.protocols().unwrap();
+    reader.dev_snmp6_all().unwrap();
+    reader.wireless().unwrap();
+    reader.packet().unwrap();
+    reader.netlink().unwrap();
+    reader.ptype().unwrap();
+    reader.nfsd_stats().unwrap();
+    reader.softnet_stat().unwrap();
+
+    reader.hostname().unwrap();
+    reader.kernel_version().unwrap();
+    reader.pid_max().unwrap();
+
+    reader.process_stat(4242).unwrap();
+    reader.process_mem(4242).unwrap();
+    reader.process_io(4242).unwrap();
+    reader.process_status(4242).unwrap();
+    reader.build_inode_pid_map().unwrap();
+}
+
+#[test]
+fn bundle_missing_file() {
+    let reader = ProcfsReader::from_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+
+    let err = reader.process_stat(1).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(
+        reader
+            .newest_process(&LoadAvg {
+                last_pid: 1,
+                ..Default::default()
+            })
+            .unwrap(),
+        None
+    );
+}
@@ -0,0 +1,57 @@
+extern crate linux_stats;
+
+use linux_stats::{MemInfo, ProcfsReader, Stat};
+
+use std::fs;
+use std::path::PathBuf;
+
+fn scratch_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("linux-stats-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+    root
+}
+
+#[test]
+fn stat_cache_refresh() {
+    let root = scratch_root("stat-cache");
+    fs::write(root.join("stat"), "cpu  10 0 10 100\nctxt 1\n").unwrap();
+
+    let mut cache = ProcfsReader::from_root(&root).stat_cache().unwrap();
+    assert_eq!(cache.get(), &Stat::default());
+    assert_eq!(cache.refresh().unwrap().ctxt, 1);
+
+    fs::write(root.join("stat"), "cpu  20 0 20 200\nctxt 22\n").unwrap();
+    let stat = cache.refresh().unwrap();
+    assert_eq!(stat.ctxt, 22);
+    assert_eq!(stat.cpu, vec![20, 0, 20, 200]);
+    assert_eq!(cache.get().ctxt, 22);
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn meminfo_cache_refresh() {
+    let root = scratch_root("meminfo-cache");
+    fs::write(root.join("meminfo"), "MemTotal:  100 kB\nMemFree:  40 kB\n").unwrap();
+
+    let mut cache = ProcfsReader::from_root(&root).meminfo_cache().unwrap();
+    assert_eq!(cache.refresh().unwrap().mem_free, 40);
+
+    fs::write(root.join("meminfo"), "MemTotal:  100 kB\nMemFree:  5 kB\n").unwrap();
+    assert_eq!(cache.refresh().unwrap().mem_free, 5);
+
+    // Not UTF-8, so the refresh fails even though parsing is lenient.
+    fs::write(root.join("meminfo"), b"MemTotal:  \xff kB\n").unwrap();
+    assert!(cache.refresh().is_err());
+    assert_eq!(cache.get().mem_free, 5);
+    assert_ne!(cache.get(), &MemInfo::default());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn cache_missing_file() {
+    assert!(ProcfsReader::from_root("/nonexistent")
+        .stat_cache()
+        .is_err());
+}
@@ -0,0 +1,51 @@
+extern crate linux_stats;
+
+use linux_stats::{CpuInfo, ProcfsReader};
+
+#[test]
+fn cpuinfo() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let cpus = reader.cpuinfo().unwrap();
+
+    let flags = "fpu vme de pse tsc msr pae mce cx8 apic sep mtrr"
+        .split(' ')
+        .map(str::to_owned)
+        .collect::<Vec<String>>();
+
+    assert_eq!(cpus.len(), 2);
+    assert_eq!(
+        cpus[0],
+        CpuInfo {
+            processor: 0,
+            vendor_id: "GenuineIntel".to_owned(),
+            model_name: "Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz".to_owned(),
+            cpu_mhz: 1992.002,
+            cache_size_kb: 8192,
+            core_id: 0,
+            physical_id: 0,
+            flags: flags.clone(),
+        }
+    );
+    assert_eq!(cpus[1].processor, 1);
+    assert_eq!(cpus[1].core_id, 1);
+    assert_eq!(cpus[1].cpu_mhz, 2103.517);
+    assert_eq!(cpus[1].flags, flags);
+}
+
+#[test]
+fn cpuinfo_sparse_block() {
+    // e.g. arm64, which reports none of the x86 identification keys.
+    let cpu = "processor\t: 3\nBogoMIPS\t: 108.00\nCPU implementer\t: 0x41\n"
+        .parse::<CpuInfo>()
+        .unwrap();
+
+    assert_eq!(cpu.processor, 3);
+    assert_eq!(cpu.model_name, "");
+    assert!(cpu.flags.is_empty());
+}
+
+#[test]
+fn cpuinfo_malformed() {
+    assert!("processor\t: zero\n".parse::<CpuInfo>().is_err());
+    assert!("cpu MHz\t\t: fast\n".parse::<CpuInfo>().is_err());
+}
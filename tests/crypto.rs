@@ -0,0 +1,34 @@
+extern crate linux_stats;
+
+use linux_stats::{Crypto, ProcfsReader};
+
+#[test]
+fn crypto() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let algorithms = reader.crypto().unwrap();
+
+    assert_eq!(algorithms.len(), 2);
+
+    assert_eq!(algorithms[0].name, "sha256");
+    assert_eq!(algorithms[0].driver, "sha256-avx2");
+    assert_eq!(algorithms[0].module, "sha256_ssse3");
+    assert_eq!(algorithms[0].priority, 170);
+    assert_eq!(algorithms[0].type_, "shash");
+    assert_eq!(algorithms[0].fields["digestsize"], "32");
+
+    assert_eq!(algorithms[1].name, "aes");
+    assert_eq!(algorithms[1].module, "kernel");
+    assert_eq!(algorithms[1].priority, 300);
+    assert_eq!(algorithms[1].type_, "cipher");
+    assert_eq!(algorithms[1].fields["min keysize"], "16");
+    assert_eq!(algorithms[1].fields["max keysize"], "32");
+    assert!(!algorithms[1].fields.contains_key("name"));
+}
+
+#[test]
+fn crypto_malformed() {
+    let err = "name         : aes\npriority     : high\n"
+        .parse::<Crypto>()
+        .unwrap_err();
+    assert_eq!(err.field(), "priority");
+}
@@ -0,0 +1,57 @@
+extern crate linux_stats;
+
+use linux_stats::{DiskStat, ProcfsReader};
+
+#[test]
+fn diskstats_1() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let disks = reader.diskstats().unwrap();
+
+    assert_eq!(disks.len(), 5);
+    assert_eq!(
+        disks[1],
+        DiskStat {
+            major: 259,
+            minor: 0,
+            name: "nvme0n1".to_owned(),
+            reads_completed: 410593,
+            reads_merged: 107226,
+            sectors_read: 30364316,
+            time_reading: 72815,
+            writes_completed: 1140736,
+            writes_merged: 883204,
+            sectors_written: 61296146,
+            time_writing: 1230946,
+            ios_in_progress: 0,
+            time_in_io: 498496,
+            weighted_time_in_io: 1346018,
+            discards_completed: Some(18734),
+            discards_merged: Some(0),
+            sectors_discarded: Some(203463040),
+            time_discarding: Some(6102),
+            flushes_completed: Some(97312),
+            time_flushing: Some(36154),
+        }
+    );
+    assert_eq!(disks[4].name, "dm-0");
+}
+
+#[test]
+fn diskstats_old_kernel() {
+    let disk = "   8       0 sda 9811 2517 554514 53588 5349 9389 147184 31520 0 32436 85120"
+        .parse::<DiskStat>()
+        .unwrap();
+
+    assert_eq!(disk.name, "sda");
+    assert_eq!(disk.weighted_time_in_io, 85120);
+    assert_eq!(disk.discards_completed, None);
+    assert_eq!(disk.time_flushing, None);
+}
+
+#[test]
+fn diskstats_malformed() {
+    assert!("   8       0 sda 9811 2517".parse::<DiskStat>().is_err());
+    assert!("   8       0 sda 1 2 3 4 5 6 7 8 9 10 11 x"
+        .parse::<DiskStat>()
+        .is_err());
+}
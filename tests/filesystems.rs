@@ -0,0 +1,33 @@
+extern crate linux_stats;
+
+use linux_stats::{Filesystem, ProcfsReader};
+
+#[test]
+fn filesystem_lines() {
+    assert_eq!(
+        "nodev\tproc".parse::<Filesystem>().unwrap(),
+        Filesystem {
+            nodev: true,
+            name: "proc".to_owned(),
+        }
+    );
+    assert_eq!(
+        "\text4".parse::<Filesystem>().unwrap(),
+        Filesystem {
+            nodev: false,
+            name: "ext4".to_owned(),
+        }
+    );
+    assert!("nodev".parse::<Filesystem>().is_err());
+}
+
+#[test]
+fn filesystems_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let filesystems = reader.filesystems().unwrap();
+
+    assert_eq!(filesystems.len(), 7);
+    assert_eq!(filesystems.iter().filter(|fs| fs.nodev).count(), 4);
+    assert_eq!(filesystems[3].name, "ext3");
+    assert!(!filesystems[6].nodev);
+}
@@ -0,0 +1,34 @@
+extern crate linux_stats;
+
+use linux_stats::{MemInfo, SocketState, Stat};
+
+use std::io::Cursor;
+
+#[test]
+fn stat_from_reader() {
+    let content = include_str!("./stat-2");
+    let stat = Stat::from_reader(Cursor::new(content)).unwrap();
+    assert_eq!(stat, content.parse::<Stat>().unwrap());
+    assert_eq!(stat.btime, 1568127349);
+
+    // Parsed leniently, like `str::parse`.
+    let stat = Stat::from_reader(Cursor::new("cpu  1 x\n")).unwrap();
+    assert_eq!(stat.cpu, vec![1, 0]);
+}
+
+#[test]
+fn meminfo_from_reader() {
+    let content = include_str!("./meminfo-1");
+    let meminfo = MemInfo::from_reader(content.as_bytes()).unwrap();
+    assert_eq!(meminfo, content.parse::<MemInfo>().unwrap());
+    assert_eq!(meminfo.mem_total, 3521920);
+}
+
+#[test]
+fn sockets_from_reader() {
+    let content = include_str!("./proc/net/tcp");
+    let sockets = linux_stats::sockets_from_reader(Cursor::new(content)).unwrap();
+    assert_eq!(sockets.len(), 4);
+    assert_eq!(sockets[0].local_port, 22);
+    assert_eq!(sockets[2].state, SocketState::Established);
+}
@@ -0,0 +1,31 @@
+extern crate linux_stats;
+
+use linux_stats::{IgmpEntry, ProcfsReader};
+
+use std::net::Ipv4Addr;
+
+#[test]
+fn igmp() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let entries = reader.igmp().unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].device, "lo");
+    assert_eq!(entries[0].group, Ipv4Addr::new(224, 0, 0, 1));
+
+    // Both groups of eth0 carry its index and name.
+    assert_eq!(
+        entries[1],
+        IgmpEntry {
+            index: 2,
+            device: "eth0".to_owned(),
+            group: Ipv4Addr::new(224, 0, 0, 251),
+            users: 1,
+            timer: "0:00000000".to_owned(),
+            reporter: false,
+        }
+    );
+    assert_eq!(entries[2].index, 2);
+    assert_eq!(entries[2].device, "eth0");
+    assert!(entries[2].reporter);
+}
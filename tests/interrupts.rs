@@ -0,0 +1,33 @@
+extern crate linux_stats;
+
+use linux_stats::{Interrupt, Irq, ProcfsReader};
+
+#[test]
+fn interrupts() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let interrupts = reader.interrupts().unwrap();
+
+    assert_eq!(interrupts.len(), 10);
+    assert_eq!(
+        interrupts[0],
+        Interrupt {
+            irq: Irq::Number(0),
+            counts: vec![36, 0, 0, 0],
+            description: "IR-IO-APIC 2-edge timer".to_owned(),
+        }
+    );
+    assert_eq!(interrupts[4].irq, Irq::Number(129));
+    assert_eq!(interrupts[4].counts, vec![104823, 0, 35310, 0]);
+    assert_eq!(interrupts[4].description, "IR-PCI-MSI 327680-edge xhci_hcd");
+
+    assert_eq!(interrupts[6].irq, Irq::Named("LOC".to_owned()));
+    assert_eq!(
+        interrupts[6].counts,
+        vec![4853104, 4177235, 4631942, 4082741]
+    );
+    assert_eq!(interrupts[6].description, "Local timer interrupts");
+
+    assert_eq!(interrupts[8].irq, Irq::Named("ERR".to_owned()));
+    assert_eq!(interrupts[8].counts, vec![0]);
+    assert_eq!(interrupts[8].description, "");
+}
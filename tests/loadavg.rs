@@ -0,0 +1,27 @@
+extern crate linux_stats;
+
+use linux_stats::LoadAvg;
+
+#[test]
+fn loadavg() {
+    let loadavg = "0.41 0.32 0.28 1/834 12345\n".parse::<LoadAvg>().unwrap();
+
+    assert_eq!(
+        loadavg,
+        LoadAvg {
+            one: 0.41,
+            five: 0.32,
+            fifteen: 0.28,
+            runnable: 1,
+            total: 834,
+            last_pid: 12345,
+        }
+    );
+}
+
+#[test]
+fn loadavg_malformed() {
+    assert!("".parse::<LoadAvg>().is_err());
+    assert!("0.41 0.32 0.28 834 12345".parse::<LoadAvg>().is_err());
+    assert!("0.41 0.32 abc 1/834 12345".parse::<LoadAvg>().is_err());
+}
@@ -0,0 +1,49 @@
+extern crate linux_stats;
+
+use linux_stats::{MdArray, MdStat, ProcfsReader};
+
+#[test]
+fn mdstat() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let mdstat = reader.mdstat().unwrap();
+
+    assert_eq!(
+        mdstat.personalities,
+        vec!["raid1", "raid6", "raid5", "raid4"]
+    );
+    assert_eq!(mdstat.arrays.len(), 3);
+
+    assert_eq!(
+        mdstat.arrays[0],
+        MdArray {
+            name: "md1".to_owned(),
+            state: "active".to_owned(),
+            level: Some("raid1".to_owned()),
+            devices: vec![("sdb1".to_owned(), 1), ("sda1".to_owned(), 0)],
+            status: "_U".to_owned(),
+            sync_progress: None,
+        }
+    );
+    assert!(mdstat.arrays[0].is_degraded());
+
+    assert_eq!(mdstat.arrays[1].name, "md0");
+    assert_eq!(mdstat.arrays[1].devices.len(), 3);
+    assert_eq!(mdstat.arrays[1].status, "UUU");
+    assert_eq!(mdstat.arrays[1].sync_progress, Some(27.3));
+    assert!(!mdstat.arrays[1].is_degraded());
+
+    assert_eq!(mdstat.arrays[2].state, "inactive");
+    assert_eq!(mdstat.arrays[2].level, None);
+    assert_eq!(mdstat.arrays[2].devices, vec![("sde".to_owned(), 0)]);
+}
+
+#[test]
+fn mdstat_malformed() {
+    let err = "md0 : active raid1 sda1\n".parse::<MdStat>().unwrap_err();
+    assert_eq!(err.field(), "device");
+
+    let err = "md0 : active raid1 sda1[0]\n      [==>....]  resync = x%\n"
+        .parse::<MdStat>()
+        .unwrap_err();
+    assert_eq!(err.field(), "progress");
+}
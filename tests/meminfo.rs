@@ -1,102 +1,124 @@
 extern crate linux_stats;
 
-use linux_stats::MemInfo;
-
-const MEMINFO_1: MemInfo = MemInfo {
-    mem_total: 3521920,
-    mem_free: 1878240,
-    mem_available: 2275916,
-    bufers: 35428,
-    cached: 386132,
-    swap_cached: 0,
-    active: 134352,
-    inactive: 266336,
-    active_anon: 1094728,
-    inactive_anon: 17664,
-    active_file: 134352,
-    inactive_file: 266336,
-    unevictable: 3660,
-    mlocked: 3660,
-    swap_total: 0,
-    swap_free: 0,
-    dirty: 12,
-    writeback: 0,
-    anon_pages: 1095172,
-    mapped: 71384,
-    shmem: 18456,
-    slab: 50800,
-    s_reclaimable: 24684,
-    s_unreclaim: 26116,
-    kernel_stack: 5584,
-    page_tables: 6184,
-    nfs_unstable: 0,
-    bounce: 0,
-    writeback_tmp: 0,
-    commit_limit: 1760960,
-    committed_as: 2064016,
-    vmalloc_total: 34359738367,
-    vmalloc_used: 0,
-    vmalloc_chunk: 0,
-    hardware_corrupted: 0,
-    anon_huge_pages: 1013760,
-    cma_total: 0,
-    cma_free: 0,
-    huge_pages_total: 0,
-    huge_pages_free: 0,
-    huge_pages_rsvd: 0,
-    huge_pages_surp: 0,
-    hugepagesize: 2048,
-    direct_map_4k: 67520,
-    direct_map_2m: 3602432,
-};
-
-const MEMINFO_2: MemInfo = MemInfo {
-    mem_total: 32828552,
-    mem_free: 12195628,
-    mem_available: 13725248,
-    bufers: 185048,
-    cached: 1876616,
-    swap_cached: 0,
-    active: 806832,
-    inactive: 1015204,
-    active_anon: 1531372,
-    inactive_anon: 105576,
-    active_file: 806832,
-    inactive_file: 1015204,
-    unevictable: 132464,
-    mlocked: 0,
-    swap_total: 4194280,
-    swap_free: 4194280,
-    dirty: 224,
-    writeback: 0,
-    anon_pages: 1529596,
-    mapped: 16887024,
-    shmem: 0,
-    slab: 354316,
-    s_reclaimable: 155152,
-    s_unreclaim: 199164,
-    kernel_stack: 8912,
-    page_tables: 47852,
-    nfs_unstable: 0,
-    bounce: 0,
-    writeback_tmp: 0,
-    commit_limit: 20608556,
-    committed_as: 20066912,
-    vmalloc_total: 34359738367,
-    vmalloc_used: 0,
-    vmalloc_chunk: 0,
-    hardware_corrupted: 0,
-    anon_huge_pages: 0,
-    cma_total: 0,
-    cma_free: 0,
-    huge_pages_total: 0,
-    huge_pages_free: 0,
-    huge_pages_rsvd: 0,
-    huge_pages_surp: 0,
-    hugepagesize: 2048,
-    direct_map_4k: 215212,
-    direct_map_2m: 8062976,
-};
+use linux_stats::{MemInfo, MemUnit, ParseOptions};
+
+use std::collections::HashMap;
+
+#[allow(deprecated)]
+fn expected_meminfo_1() -> MemInfo {
+    MemInfo {
+        mem_total: 3521920,
+        mem_free: 1878240,
+        mem_available: 2275916,
+        buffers: 35428,
+        bufers: 35428,
+        cached: 386132,
+        swap_cached: 0,
+        active: 1229080,
+        inactive: 284000,
+        active_anon: 1094728,
+        inactive_anon: 17664,
+        active_file: 134352,
+        inactive_file: 266336,
+        unevictable: 3660,
+        mlocked: 3660,
+        swap_total: 0,
+        swap_free: 0,
+        dirty: 12,
+        writeback: 0,
+        anon_pages: 1095172,
+        mapped: 71384,
+        shmem: 18456,
+        slab: 50800,
+        s_reclaimable: 24684,
+        s_unreclaim: 26116,
+        kernel_stack: 5584,
+        page_tables: 6184,
+        nfs_unstable: 0,
+        bounce: 0,
+        writeback_tmp: 0,
+        commit_limit: 1760960,
+        committed_as: 2064016,
+        vmalloc_total: 34359738367,
+        vmalloc_used: 0,
+        vmalloc_chunk: 0,
+        hardware_corrupted: 0,
+        anon_huge_pages: 1013760,
+        cma_total: 0,
+        cma_free: 0,
+        huge_pages_total: 0,
+        huge_pages_free: 0,
+        huge_pages_rsvd: 0,
+        huge_pages_surp: 0,
+        hugepagesize: 2048,
+        direct_map_4k: 67520,
+        direct_map_2m: 3602432,
+        extra: HashMap::new(),
+    }
+}
+
+#[allow(deprecated)]
+fn expected_meminfo_2() -> MemInfo {
+    MemInfo {
+        mem_total: 32828552,
+        mem_free: 12195628,
+        mem_available: 13725248,
+        buffers: 185048,
+        bufers: 185048,
+        cached: 1876616,
+        swap_cached: 0,
+        active: 2338204,
+        inactive: 1120780,
+        active_anon: 1531372,
+        inactive_anon: 105576,
+        active_file: 806832,
+        inactive_file: 1015204,
+        unevictable: 132464,
+        mlocked: 0,
+        swap_total: 4194280,
+        swap_free: 4194280,
+        dirty: 224,
+        writeback: 0,
+        anon_pages: 1529596,
+        mapped: 16887024,
+        shmem: 239632,
+        slab: 354316,
+        s_reclaimable: 155152,
+        s_unreclaim: 199164,
+        kernel_stack: 8912,
+        page_tables: 47852,
+        nfs_unstable: 0,
+        bounce: 0,
+        writeback_tmp: 0,
+        commit_limit: 20608556,
+        committed_as: 20066912,
+        vmalloc_total: 34359738367,
+        vmalloc_used: 0,
+        vmalloc_chunk: 0,
+        hardware_corrupted: 0,
+        anon_huge_pages: 0,
+        cma_total: 0,
+        cma_free: 0,
+        huge_pages_total: 0,
+        huge_pages_free: 0,
+        huge_pages_rsvd: 0,
+        huge_pages_surp: 0,
+        hugepagesize: 2048,
+        direct_map_4k: 215212,
+        direct_map_2m: 8062976,
+        extra: [
+            ("KReclaimable", 155152),
+            ("Percpu", 2656),
+            ("ShmemHugePages", 0),
+            ("ShmemPmdMapped", 0),
+            ("Hugetlb", 0),
+            ("DirectMap1G", 25165824),
+        ]
+        .iter()
+        .map(|&(key, value)| (key.to_owned(), value))
+        .collect(),
+    }
+}
 
 const MEMINFO_1_RAW: &str = include_str!("./meminfo-1");
 const MEMINFO_2_RAW: &str = include_str!("./meminfo-2");
@@ -108,10 +130,188 @@ fn meminfo_empty() {
 
 #[test]
 fn meminfo_1() {
-    assert_eq!(MEMINFO_1_RAW.parse::<MemInfo>().unwrap(), MEMINFO_1);
+    assert_eq!(
+        MEMINFO_1_RAW.parse::<MemInfo>().unwrap(),
+        expected_meminfo_1()
+    );
 }
 
 #[test]
 fn meminfo_2() {
-    assert_eq!(MEMINFO_2_RAW.parse::<MemInfo>().unwrap(), MEMINFO_2);
+    assert_eq!(
+        MEMINFO_2_RAW.parse::<MemInfo>().unwrap(),
+        expected_meminfo_2()
+    );
+}
+
+#[test]
+fn meminfo_bytes() {
+    let meminfo_1 = expected_meminfo_1();
+    assert_eq!(meminfo_1.mem_total_bytes(), meminfo_1.mem_total * 1024);
+    assert_eq!(meminfo_1.mem_total_bytes(), 3606446080);
+
+    let meminfo_2 = expected_meminfo_2();
+    assert_eq!(meminfo_2.swap_free_bytes(), 4194280 * 1024);
+    assert_eq!(meminfo_2.hugepagesize_bytes(), 2 * 1024 * 1024);
+}
+
+#[test]
+fn meminfo_extra() {
+    let meminfo = "MemTotal:       32828552 kB\nZswap:              1234 kB\n"
+        .parse::<MemInfo>()
+        .unwrap();
+
+    assert_eq!(meminfo.mem_total, 32828552);
+    assert_eq!(meminfo.extra.len(), 1);
+    assert_eq!(meminfo.extra.get("Zswap"), Some(&1234));
+}
+
+#[test]
+fn meminfo_shared_prefixes() {
+    let meminfo = "Active(anon):    1531372 kB
+Active(file):     806832 kB
+Active:          2338204 kB
+Inactive(file):  1015204 kB
+Inactive:        1120780 kB
+SwapCached:           16 kB
+Cached:          1876616 kB
+SReclaimable:     155152 kB
+Slab:             354316 kB
+WritebackTmp:          8 kB
+Writeback:             4 kB
+ShmemHugePages:        0 kB
+Shmem:            239632 kB
+"
+    .parse::<MemInfo>()
+    .unwrap();
+
+    assert_eq!(meminfo.active, 2338204);
+    assert_eq!(meminfo.active_anon, 1531372);
+    assert_eq!(meminfo.active_file, 806832);
+    assert_eq!(meminfo.inactive, 1120780);
+    assert_eq!(meminfo.inactive_file, 1015204);
+    assert_eq!(meminfo.cached, 1876616);
+    assert_eq!(meminfo.swap_cached, 16);
+    assert_eq!(meminfo.slab, 354316);
+    assert_eq!(meminfo.s_reclaimable, 155152);
+    assert_eq!(meminfo.writeback, 4);
+    assert_eq!(meminfo.writeback_tmp, 8);
+    assert_eq!(meminfo.shmem, 239632);
+    assert_eq!(meminfo.extra.get("ShmemHugePages"), Some(&0));
+}
+
+#[test]
+fn meminfo_malformed() {
+    let strict = ParseOptions::strict();
+
+    let content = "MemTotal:       8167848 kB\nMemFree:        garbage kB\n";
+    let err = MemInfo::from_str_with(content, strict).unwrap_err();
+    assert_eq!(err.line(), "MemFree:        garbage kB");
+
+    assert!(MemInfo::from_str_with("MemTotal:\n", strict).is_err());
+}
+
+#[test]
+fn meminfo_parse_options() {
+    let content = "MemTotal:       8167848 kB\nMemFree:        garbage kB\nCached:  1024 kB\n";
+
+    let err = MemInfo::from_str_with(content, ParseOptions::strict()).unwrap_err();
+    assert_eq!(err.field(), "value");
+
+    let meminfo = MemInfo::from_str_with(content, ParseOptions::lenient()).unwrap();
+    assert_eq!(meminfo.mem_total, 8167848);
+    assert_eq!(meminfo.mem_free, 0);
+    assert_eq!(meminfo.cached, 1024);
+
+    // `FromStr` is lenient.
+    assert_eq!(content.parse::<MemInfo>().unwrap(), meminfo);
+}
+
+#[test]
+fn meminfo_used() {
+    let meminfo_1 = include_str!("./meminfo-1").parse::<MemInfo>().unwrap();
+    // 3521920 - 1878240 - 35428 - 386132 - 24684
+    assert_eq!(meminfo_1.used(), 1197436);
+    assert!((meminfo_1.used_percent() - 33.99952).abs() < 1e-4);
+    assert_eq!(meminfo_1.swap_used(), 0);
+    assert_eq!(meminfo_1.swap_used_percent(), 0.0);
+
+    let meminfo = "SwapTotal:       4194280 kB\nSwapFree:        4000000 kB\n"
+        .parse::<MemInfo>()
+        .unwrap();
+    assert_eq!(meminfo.swap_used(), 194280);
+    assert!((meminfo.swap_used_percent() - 4.63208).abs() < 1e-4);
+    assert_eq!(meminfo.used(), 0);
+    assert_eq!(meminfo.used_percent(), 0.0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn meminfo_buffers_alias() {
+    let meminfo = include_str!("./meminfo-1").parse::<MemInfo>().unwrap();
+    assert_eq!(meminfo.buffers, 35428);
+    assert_eq!(meminfo.buffers, meminfo.bufers);
+    assert_eq!(meminfo.bufers_bytes(), meminfo.buffers_bytes());
+}
+
+#[test]
+fn meminfo_line_order_independent() {
+    let raw = include_str!("./meminfo-1");
+    let reversed = raw.lines().rev().collect::<Vec<_>>().join("\n");
+
+    assert_eq!(reversed.parse::<MemInfo>().unwrap(), expected_meminfo_1());
+    assert_eq!(raw.parse::<MemInfo>().unwrap(), expected_meminfo_1());
+}
+
+#[test]
+fn meminfo_field_unit() {
+    assert_eq!(MemInfo::field_unit("mem_total"), Some(MemUnit::Kilobytes));
+    assert_eq!(
+        MemInfo::field_unit("hugepagesize"),
+        Some(MemUnit::Kilobytes)
+    );
+    assert_eq!(MemInfo::field_unit("bufers"), Some(MemUnit::Kilobytes));
+    assert_eq!(
+        MemInfo::field_unit("huge_pages_total"),
+        Some(MemUnit::Count)
+    );
+    assert_eq!(MemInfo::field_unit("huge_pages_surp"), Some(MemUnit::Count));
+    assert_eq!(MemInfo::field_unit("extra"), None);
+    assert_eq!(MemInfo::field_unit("MemTotal"), None);
+}
+
+#[test]
+fn meminfo_fields() {
+    let meminfo = expected_meminfo_1();
+    let fields = meminfo.fields().collect::<HashMap<&str, u64>>();
+
+    assert_eq!(fields.len(), 45);
+    assert_eq!(fields["mem_total"], 3521920);
+    assert_eq!(fields["buffers"], 35428);
+    assert_eq!(fields["huge_pages_total"], 0);
+    assert_eq!(fields["direct_map_2m"], meminfo.direct_map_2m);
+    assert!(!fields.contains_key("bufers"));
+
+    // Every field has a known unit.
+    assert!(meminfo
+        .fields()
+        .all(|(name, _)| MemInfo::field_unit(name).is_some()));
+}
+
+#[test]
+fn meminfo_available_estimate() {
+    let meminfo_1 = include_str!("./meminfo-1").parse::<MemInfo>().unwrap();
+    assert_eq!(meminfo_1.available_estimate(), meminfo_1.mem_available);
+
+    let meminfo = "MemTotal:        4000000 kB
+MemFree:         1000000 kB
+Cached:           500000 kB
+SReclaimable:      60000 kB
+"
+    .parse::<MemInfo>()
+    .unwrap();
+    assert_eq!(meminfo.mem_available, 0);
+    // min_free_kbytes = sqrt(16 * 4000000) = 8000, low watermark = 10000.
+    // (1000000 - 10000) + (500000 - 10000) + (60000 - 10000)
+    assert_eq!(meminfo.available_estimate(), 1530000);
 }
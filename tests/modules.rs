@@ -0,0 +1,35 @@
+extern crate linux_stats;
+
+use linux_stats::{Module, ProcfsReader};
+
+#[test]
+fn modules() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let modules = reader.modules().unwrap();
+
+    assert_eq!(modules.len(), 5);
+    assert_eq!(
+        modules[2],
+        Module {
+            name: "nf_nat".to_owned(),
+            size: 49152,
+            ref_count: 2,
+            used_by: vec!["xt_MASQUERADE".to_owned(), "nft_chain_nat".to_owned()],
+            state: "Live".to_owned(),
+            offset: Some(0xffffffffc0a5e000),
+        }
+    );
+    assert!(modules[0].used_by.is_empty());
+    assert_eq!(modules[3].used_by.len(), 3);
+    assert_eq!(modules[4].offset, Some(0));
+}
+
+#[test]
+fn module_without_offset() {
+    let module = "loop 32768 0 - Live".parse::<Module>().unwrap();
+    assert_eq!(module.offset, None);
+    assert_eq!(module.state, "Live");
+
+    let err = "loop 32768 0".parse::<Module>().unwrap_err();
+    assert_eq!(err.field(), "used_by");
+}
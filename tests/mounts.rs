@@ -0,0 +1,44 @@
+extern crate linux_stats;
+
+use linux_stats::{Mount, ProcfsReader};
+
+#[test]
+fn mounts() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let mounts = reader.mounts().unwrap();
+
+    assert_eq!(mounts.len(), 5);
+    assert_eq!(
+        mounts[2],
+        Mount {
+            device: "/dev/mapper/vg0-root".to_owned(),
+            mount_point: "/".to_owned(),
+            fs_type: "ext4".to_owned(),
+            options: vec![
+                "rw".to_owned(),
+                "relatime".to_owned(),
+                "errors=remount-ro".to_owned(),
+            ],
+            dump: 0,
+            pass: 1,
+        }
+    );
+    assert_eq!(mounts[3].options.len(), 8);
+}
+
+#[test]
+fn mounts_escaped_space() {
+    let mount = "/dev/sdb1 /media/user/USB\\040Drive\\011(2) vfat rw,nosuid 0 0"
+        .parse::<Mount>()
+        .unwrap();
+
+    assert_eq!(mount.device, "/dev/sdb1");
+    assert_eq!(mount.mount_point, "/media/user/USB Drive\t(2)");
+    assert_eq!(mount.fs_type, "vfat");
+}
+
+#[test]
+fn mounts_malformed() {
+    assert!("/dev/sdb1 /mnt vfat rw".parse::<Mount>().is_err());
+    assert!("/dev/sdb1 /mnt vfat rw x 0".parse::<Mount>().is_err());
+}
@@ -0,0 +1,103 @@
+extern crate linux_stats;
+
+use linux_stats::{NetDev, NetDevRate, ProcfsReader};
+
+use std::time::Duration;
+
+#[test]
+fn net_dev_line() {
+    let dev = "  eth0: 1215645   10431    0   12    0     0          0       108   978103    7123    0    0    0     0       0          0"
+        .parse::<NetDev>()
+        .unwrap();
+
+    assert_eq!(
+        dev,
+        NetDev {
+            interface: "eth0".to_owned(),
+            rx_bytes: 1215645,
+            rx_packets: 10431,
+            rx_errs: 0,
+            rx_drop: 12,
+            rx_fifo: 0,
+            rx_frame: 0,
+            rx_compressed: 0,
+            rx_multicast: 108,
+            tx_bytes: 978103,
+            tx_packets: 7123,
+            tx_errs: 0,
+            tx_drop: 0,
+            tx_fifo: 0,
+            tx_colls: 0,
+            tx_carrier: 0,
+            tx_compressed: 0,
+        }
+    );
+}
+
+#[test]
+fn net_dev_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let devs = reader.net_dev().unwrap();
+
+    assert_eq!(devs.len(), 3);
+    assert_eq!(devs[0].interface, "lo");
+    assert_eq!(devs[0].rx_bytes, devs[0].tx_bytes);
+    assert_eq!(devs[1].interface, "eth0");
+    assert_eq!(devs[1].tx_packets, 7123);
+
+    // No space between the colon and a wide byte counter.
+    assert_eq!(devs[2].interface, "wlan0");
+    assert_eq!(devs[2].rx_bytes, 4294967296);
+    assert_eq!(devs[2].rx_packets, 3000000);
+    assert_eq!(devs[2].tx_compressed, 14);
+}
+
+#[test]
+fn net_dev_malformed() {
+    assert!("eth0 1 2 3".parse::<NetDev>().is_err());
+    assert!("eth0: 1 2 3".parse::<NetDev>().is_err());
+}
+
+#[test]
+fn net_dev_rate_since() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let previous = reader.net_dev().unwrap();
+    let mut current = previous.clone();
+    current[1].rx_bytes += 125000;
+    current[1].rx_packets += 100;
+    current[1].tx_bytes += 5000;
+    current[1].tx_packets += 10;
+
+    let second = Duration::from_secs(1);
+    assert_eq!(
+        current[1].rate_since(&previous[1], second),
+        NetDevRate {
+            interface: "eth0".to_owned(),
+            rx_bytes: 125000.0,
+            rx_packets: 100.0,
+            tx_bytes: 5000.0,
+            tx_packets: 10.0,
+        }
+    );
+    assert_eq!(
+        current[1]
+            .rate_since(&previous[1], Duration::from_millis(500))
+            .rx_bytes,
+        250000.0
+    );
+
+    // Counter reset, e.g. the interface was recreated.
+    assert_eq!(previous[1].rate_since(&current[1], second).rx_bytes, 0.0);
+    assert_eq!(
+        current[1].rate_since(&previous[1], Duration::ZERO).rx_bytes,
+        0.0
+    );
+
+    // Matched by name: `lo` is gone from the previous sample.
+    let rates = linux_stats::net_dev_rates(&current, &previous[1..], second);
+    assert_eq!(rates.len(), 2);
+    assert_eq!(rates[0].interface, "eth0");
+    assert_eq!(rates[0].tx_packets, 10.0);
+    assert_eq!(rates[1].interface, "wlan0");
+    assert_eq!(rates[1].rx_bytes, 0.0);
+}
@@ -0,0 +1,43 @@
+extern crate linux_stats;
+
+use linux_stats::{NetlinkSocket, ProcfsReader};
+
+#[test]
+fn netlink() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let sockets = reader.netlink().unwrap();
+
+    assert_eq!(sockets.len(), 2);
+    assert_eq!(
+        sockets[0],
+        NetlinkSocket {
+            sk: "ffff9e0b8a3c6000".to_owned(),
+            eth: 0,
+            pid: 1,
+            groups: "00000550".to_owned(),
+            rmem: 0,
+            wmem: 0,
+            dump: 0,
+            locks: 2,
+            drops: 0,
+            inode: 18093,
+        }
+    );
+    assert_eq!(sockets[1].eth, 9);
+    assert_eq!(sockets[1].pid, 4294963112);
+    assert_eq!(sockets[1].drops, 3);
+    assert_eq!(sockets[1].inode, 20871);
+}
+
+#[test]
+fn netlink_malformed() {
+    let err = "ffff9e0b8a3c6000 0 -1 00000550 0 0 0 2 0 18093"
+        .parse::<NetlinkSocket>()
+        .unwrap_err();
+    assert_eq!(err.field(), "Pid");
+
+    let err = "ffff9e0b8a3c6000 0 1 00000550 0 0 0 2 0"
+        .parse::<NetlinkSocket>()
+        .unwrap_err();
+    assert_eq!(err.field(), "Inode");
+}
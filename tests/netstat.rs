@@ -0,0 +1,38 @@
+extern crate linux_stats;
+
+use linux_stats::{NetStat, ProcfsReader};
+
+#[test]
+fn netstat_tcp_ext() {
+    let netstat = "TcpExt: SyncookiesSent TCPLostRetransmit TCPSynRetrans
+TcpExt: 0 182 1877
+"
+    .parse::<NetStat>()
+    .unwrap();
+
+    assert_eq!(netstat.get("TcpExt", "SyncookiesSent"), Some(0));
+    assert_eq!(netstat.tcp_lost_retransmit(), Some(182));
+    assert_eq!(netstat.tcp_syn_retrans(), Some(1877));
+    assert_eq!(netstat.get("TcpExt", "TCPTimeouts"), None);
+    assert_eq!(netstat.ip_in_octets(), None);
+}
+
+#[test]
+fn netstat_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let netstat = reader.netstat().unwrap();
+
+    assert_eq!(netstat.categories.len(), 2);
+    assert_eq!(netstat.listen_overflows(), Some(3));
+    assert_eq!(netstat.listen_drops(), Some(3));
+    assert_eq!(netstat.get("TcpExt", "TCPTimeouts"), Some(4021));
+    assert_eq!(netstat.ip_in_octets(), Some(9876543210));
+    assert_eq!(netstat.ip_out_octets(), Some(1234567890));
+}
+
+#[test]
+fn netstat_negative_value() {
+    assert!("TcpExt: TCPTimeouts\nTcpExt: -1\n"
+        .parse::<NetStat>()
+        .is_err());
+}
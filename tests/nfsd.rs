@@ -0,0 +1,41 @@
+extern crate linux_stats;
+
+use linux_stats::{NfsdStats, ProcfsReader};
+
+#[test]
+fn nfsd_stats() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let nfsd = reader.nfsd_stats().unwrap();
+
+    assert_eq!(nfsd.rc_hits, 12);
+    assert_eq!(nfsd.rc_misses, 3456);
+    assert_eq!(nfsd.rc_nocache, 789);
+    assert_eq!(nfsd.fh_stale, 2);
+    assert_eq!(nfsd.io_read, 1048576);
+    assert_eq!(nfsd.io_write, 524288);
+    assert_eq!(nfsd.threads, 8);
+    assert_eq!(nfsd.ra.len(), 12);
+    assert_eq!(nfsd.ra[0], 32);
+    assert_eq!(nfsd.net_count, 4260);
+    assert_eq!(nfsd.net_tcp, 4258);
+    assert_eq!(nfsd.net_tcp_conn, 17);
+    assert_eq!(nfsd.rpc_count, 4258);
+    assert_eq!(nfsd.rpc_bad, 1);
+    assert_eq!(nfsd.rpc_bad_auth, 1);
+    assert_eq!(nfsd.rpc_bad_client, 0);
+
+    assert_eq!(nfsd.procs.len(), 3);
+    assert_eq!(nfsd.procs["proc3"].len(), 22);
+    assert_eq!(nfsd.procs["proc3"][1], 100);
+    assert_eq!(nfsd.procs["proc4"], vec![1, 4257]);
+    assert_eq!(nfsd.procs["proc4ops"], vec![0, 12, 34]);
+}
+
+#[test]
+fn nfsd_stats_malformed() {
+    let err = "rpc 4258 1 0\n".parse::<NfsdStats>().unwrap_err();
+    assert_eq!(err.field(), "badauth");
+
+    let err = "io 10 x\n".parse::<NfsdStats>().unwrap_err();
+    assert_eq!(err.line(), "io 10 x");
+}
@@ -0,0 +1,42 @@
+extern crate linux_stats;
+
+use linux_stats::{PacketSocket, ProcfsReader};
+
+#[test]
+fn packet() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let sockets = reader.packet().unwrap();
+
+    assert_eq!(sockets.len(), 2);
+    assert_eq!(
+        sockets[0],
+        PacketSocket {
+            sk: "ffff8a5c4b6e1000".to_owned(),
+            ref_count: 3,
+            type_: 3,
+            proto: 0x0003,
+            iface: 2,
+            r: 1,
+            rmem: 0,
+            user: 0,
+            inode: 28447,
+        }
+    );
+    assert_eq!(sockets[1].proto, 0x88cc);
+    assert_eq!(sockets[1].rmem, 2304);
+    assert_eq!(sockets[1].user, 101);
+    assert_eq!(sockets[1].inode, 31592);
+}
+
+#[test]
+fn packet_malformed() {
+    let err = "ffff8a5c4b6e1000 3 3 zz03 2 1 0 0 28447"
+        .parse::<PacketSocket>()
+        .unwrap_err();
+    assert_eq!(err.field(), "Proto");
+
+    let err = "ffff8a5c4b6e1000 3 3 0003 2 1 0 0"
+        .parse::<PacketSocket>()
+        .unwrap_err();
+    assert_eq!(err.field(), "Inode");
+}
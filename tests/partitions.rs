@@ -0,0 +1,29 @@
+extern crate linux_stats;
+
+use linux_stats::{Partition, ProcfsReader};
+
+#[test]
+fn partitions() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let partitions = reader.partitions().unwrap();
+
+    assert_eq!(partitions.len(), 4);
+    assert_eq!(
+        partitions[0],
+        Partition {
+            major: 8,
+            minor: 0,
+            blocks: 500107608,
+            name: "sda".to_owned(),
+        }
+    );
+    assert_eq!(partitions[1].name, "sda1");
+    assert_eq!(partitions[1].size_bytes(), 536870912);
+    assert_eq!(partitions[3].major, 253);
+}
+
+#[test]
+fn partition_malformed() {
+    let err = "   8        1     524288".parse::<Partition>().unwrap_err();
+    assert_eq!(err.field(), "name");
+}
@@ -0,0 +1,85 @@
+extern crate linux_stats;
+
+use linux_stats::{Pressure, PressureRecord, ProcfsReader};
+
+fn reader() -> ProcfsReader {
+    ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"))
+}
+
+#[test]
+fn pressure_some_full() {
+    let pressure = "some avg10=0.00 avg60=0.12 avg300=0.05 total=3325876
+full avg10=0.00 avg60=0.08 avg300=0.02 total=2410151
+"
+    .parse::<Pressure>()
+    .unwrap();
+
+    assert_eq!(
+        pressure,
+        Pressure {
+            some: PressureRecord {
+                avg10: 0.0,
+                avg60: 0.12,
+                avg300: 0.05,
+                total: 3325876,
+            },
+            full: Some(PressureRecord {
+                avg10: 0.0,
+                avg60: 0.08,
+                avg300: 0.02,
+                total: 2410151,
+            }),
+        }
+    );
+}
+
+#[test]
+fn pressure_readers() {
+    let cpu = reader().pressure_cpu().unwrap();
+    assert_eq!(cpu.some.avg10, 1.53);
+    assert_eq!(cpu.some.total, 58761459);
+    assert_eq!(cpu.full, None);
+
+    let memory = reader().pressure_memory().unwrap();
+    assert_eq!(memory.full.unwrap().total, 2410151);
+
+    let io = reader().pressure_io().unwrap();
+    assert_eq!(io.some.avg300, 1.38);
+    assert_eq!(io.full.unwrap().avg60, 2.71);
+}
+
+#[test]
+fn pressure_malformed() {
+    assert!("".parse::<Pressure>().is_err());
+    assert!("full avg10=0.00 avg60=0.08 avg300=0.02 total=1"
+        .parse::<Pressure>()
+        .is_err());
+    assert!("some avg10=0.00 avg60=0.08 total=1"
+        .parse::<Pressure>()
+        .is_err());
+}
+
+#[test]
+fn pressure_changed_since() {
+    let previous = "some avg10=1.2345 avg60=0.12 avg300=0.05 total=3325876
+full avg10=0.00 avg60=0.08 avg300=0.02 total=2410151
+"
+    .parse::<Pressure>()
+    .unwrap();
+
+    // Only the 4th decimal differs.
+    let mut current = previous;
+    current.some.avg10 = 1.2346;
+    assert!(!current.changed_since(&previous, 0.001));
+    assert!(current.changed_since(&previous, 0.00001));
+
+    let mut current = previous;
+    current.full.as_mut().unwrap().total += 1;
+    assert!(current.changed_since(&previous, 0.001));
+
+    let mut current = previous;
+    current.full = None;
+    assert!(current.changed_since(&previous, 0.001));
+
+    assert!(!previous.changed_since(&previous, 0.0));
+}
@@ -0,0 +1,164 @@
+extern crate linux_stats;
+
+use linux_stats::{PidIo, ProcessIo, ProcessMem, ProcessStat, ProcessStatus, ProcfsReader};
+
+use std::io;
+
+fn reader() -> ProcfsReader {
+    ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"))
+}
+
+#[test]
+fn process_stat() {
+    let stat = reader().process_stat(4242).unwrap();
+
+    assert_eq!(
+        stat,
+        ProcessStat {
+            pid: 4242,
+            comm: "tmux: server".to_owned(),
+            state: 'S',
+            ppid: 1,
+            pgrp: 4242,
+            session: 4242,
+            tty_nr: 0,
+            tpgid: -1,
+            flags: 4194624,
+            minflt: 1875,
+            cminflt: 0,
+            majflt: 3,
+            cmajflt: 0,
+            utime: 112,
+            stime: 47,
+            cutime: 0,
+            cstime: 0,
+            priority: 20,
+            nice: 0,
+            num_threads: 1,
+            starttime: 20538,
+            vsize: 23150592,
+            rss: 1183,
+        }
+    );
+}
+
+#[test]
+fn process_stat_tricky_comm() {
+    let stat = "77 (a) b (c)) R 1 77 77 34816 77 4194304 96 0 0 0 0 0 0 0 20 0 1 0 3074 5459968 187 18446744073709551615"
+        .parse::<ProcessStat>()
+        .unwrap();
+
+    assert_eq!(stat.comm, "a) b (c)");
+    assert_eq!(stat.state, 'R');
+    assert_eq!(stat.tty_nr, 34816);
+    assert_eq!(stat.rss, 187);
+}
+
+#[test]
+fn process_stat_malformed() {
+    assert!("77 bash R 1".parse::<ProcessStat>().is_err());
+    assert!("77 (bash) R 1 77".parse::<ProcessStat>().is_err());
+    assert!(reader().process_stat(1).is_err());
+}
+
+#[test]
+fn process_mem() {
+    let mem = "1234 567 89 10 0 200 0".parse::<ProcessMem>().unwrap();
+
+    assert_eq!(
+        mem,
+        ProcessMem {
+            size: 1234,
+            resident: 567,
+            shared: 89,
+            text: 10,
+            lib: 0,
+            data: 200,
+            dt: 0,
+        }
+    );
+    assert_eq!(mem.resident_bytes(), 567 * 4096);
+
+    let mem = reader().process_mem(4242).unwrap();
+    assert_eq!(mem.resident, 1183);
+    assert_eq!(mem.data, 154);
+}
+
+#[test]
+fn process_mem_malformed() {
+    assert!("1234 567 89".parse::<ProcessMem>().is_err());
+}
+
+#[test]
+fn process_status() {
+    let status = reader().process_status(4242).unwrap();
+
+    assert_eq!(status.name(), Some("tmux: server"));
+    assert_eq!(status.state(), Some("S (sleeping)"));
+    assert_eq!(status.vm_peak_kb(), Some(22608));
+    assert_eq!(status.vm_rss_kb(), Some(4732));
+    assert_eq!(status.vm_swap_kb(), Some(0));
+    assert_eq!(status.threads(), Some(1));
+    assert_eq!(status.voluntary_ctxt_switches(), Some(1517));
+    assert_eq!(status.nonvoluntary_ctxt_switches(), Some(12));
+    assert_eq!(status.get("Uid"), Some("1000\t1000\t1000\t1000"));
+    assert_eq!(status.get("Missing"), None);
+}
+
+#[test]
+fn process_status_missing_fields() {
+    // Kernel threads have no memory map, so the Vm* fields are absent.
+    let status = "Name:\tkthreadd\nState:\tS (sleeping)\nThreads:\t1\n"
+        .parse::<ProcessStatus>()
+        .unwrap();
+
+    assert_eq!(status.name(), Some("kthreadd"));
+    assert_eq!(status.vm_rss_kb(), None);
+    assert_eq!(status.threads(), Some(1));
+}
+
+#[test]
+fn process_cpu_time_secs() {
+    let stat = reader().process_stat(4242).unwrap();
+    let expected = (112 + 47) as f64 / linux_stats::clk_tck() as f64;
+    assert_eq!(stat.cpu_time_secs(), expected);
+
+    #[cfg(not(feature = "sysconf"))]
+    {
+        assert_eq!(linux_stats::clk_tck(), 100);
+        assert!((stat.cpu_time_secs() - 1.59).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn process_io() {
+    assert_eq!(
+        reader().process_io(4242).unwrap(),
+        ProcessIo {
+            rchar: 323934931,
+            wchar: 323929600,
+            syscr: 632687,
+            syscw: 632675,
+            read_bytes: 16384,
+            write_bytes: 2183168,
+            cancelled_write_bytes: 4096,
+        }
+    );
+
+    assert!(reader().process_io(4243).is_err());
+    assert!("rchar: lots\n".parse::<ProcessIo>().is_err());
+}
+
+#[test]
+fn pid_io() {
+    let io: PidIo = reader().pid_io(4242).unwrap();
+    assert_eq!(io, reader().process_io(4242).unwrap());
+    assert_eq!(io.read_bytes, 16384);
+    assert_eq!(io.cancelled_write_bytes, 4096);
+
+    let err = reader().pid_io(4243).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+    let err = reader().pid_io(-1).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
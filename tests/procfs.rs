@@ -0,0 +1,455 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, SocketState, SocketTimerState};
+
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::{Duration, Instant};
+
+fn reader() -> ProcfsReader {
+    ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"))
+}
+
+#[test]
+fn procfs_default_root() {
+    assert_eq!(ProcfsReader::default().root().to_str(), Some("/proc"));
+}
+
+#[test]
+fn procfs_stat() {
+    let stat = reader().stat().unwrap();
+    assert_eq!(stat.cpus.len(), 4);
+    assert_eq!(stat.btime, 1568127349);
+}
+
+#[test]
+fn procfs_stat_aggregate_only() {
+    let stat = reader().stat_aggregate_only().unwrap();
+    assert!(stat.cpus.is_empty());
+    assert_eq!(stat.cpu, reader().stat().unwrap().cpu);
+    assert_eq!(stat.btime, 1568127349);
+}
+
+#[test]
+fn procfs_meminfo() {
+    let meminfo = reader().meminfo().unwrap();
+    assert_eq!(meminfo.mem_total, 32828552);
+}
+
+#[test]
+fn procfs_tcp() {
+    let sockets = reader().tcp().unwrap();
+    assert_eq!(sockets.len(), 4);
+    assert_eq!(sockets[0].local_port, 22);
+    assert_eq!(sockets[0].state, SocketState::Listen);
+    assert_eq!(
+        sockets[2].remote_address,
+        IpAddr::V4(Ipv4Addr::new(46, 238, 65, 91))
+    );
+    assert_eq!(sockets[2].state, SocketState::Established);
+    assert_eq!(sockets[2].cwnd, Some(10));
+
+    // TIME_WAIT rows lack the trailing congestion columns.
+    assert_eq!(sockets[3].state, SocketState::TimeWait);
+    assert_eq!(sockets[3].cwnd, None);
+    assert_eq!(sockets[3].ssthresh, None);
+}
+
+#[test]
+fn procfs_tcp6() {
+    let sockets = reader().tcp6().unwrap();
+    assert_eq!(sockets.len(), 1);
+    assert_eq!(sockets[0].local_address, IpAddr::V6(Ipv6Addr::LOCALHOST));
+}
+
+#[test]
+fn procfs_udp() {
+    let sockets = reader().udp().unwrap();
+    assert_eq!(sockets.len(), 2);
+    assert_eq!(
+        sockets[0].local_address,
+        IpAddr::V4(Ipv4Addr::new(127, 0, 0, 53))
+    );
+    assert_eq!(sockets[0].local_port, 53);
+    assert_eq!(sockets[1].state, SocketState::Close);
+
+    let sockets = reader().udp6().unwrap();
+    assert_eq!(sockets[0].local_port, 5353);
+    assert_eq!(sockets[0].uid, 107);
+}
+
+#[test]
+fn procfs_socket_iter() {
+    let listening = reader()
+        .tcp_iter()
+        .unwrap()
+        .filter(|s| s.as_ref().unwrap().state == SocketState::Listen)
+        .count();
+    assert_eq!(listening, 2);
+
+    let sockets = reader()
+        .udp_iter()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(sockets.len(), 2);
+    assert_eq!(sockets[0].local_port, 53);
+
+    assert_eq!(reader().tcp6_iter().unwrap().count(), 1);
+    assert_eq!(
+        reader().udp6_iter().unwrap().count(),
+        reader().udp6().unwrap().len()
+    );
+    assert!(ProcfsReader::from_root("/nonexistent").tcp_iter().is_err());
+}
+
+#[test]
+fn procfs_socket_iter_errors() {
+    let root = std::env::temp_dir().join(format!("linux-stats-socket-iter-{}", std::process::id()));
+    fs::create_dir_all(root.join("net")).unwrap();
+
+    let tcp = fs::read(reader().root().join("net/tcp")).unwrap();
+    let mut lines = tcp.split(|b| *b == b'\n');
+    let mut content = Vec::new();
+    for line in [lines.next().unwrap(), lines.next().unwrap()] {
+        content.extend_from_slice(line);
+        content.push(b'\n');
+    }
+    content.extend_from_slice(b"   1: garbage\n");
+    content.extend_from_slice(b"   2: \xff\xfe\n");
+    content.extend_from_slice(lines.next().unwrap());
+    content.push(b'\n');
+    fs::write(root.join("net/tcp"), content).unwrap();
+
+    let mut iter = ProcfsReader::from_root(&root).tcp_iter().unwrap();
+    assert!(iter.next().unwrap().is_ok());
+    // A malformed row is reported, and iteration carries on.
+    let err = iter.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    // A read error (here, invalid UTF-8) is reported rather than silently
+    // truncating the list, and ends the iteration.
+    let err = iter.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(iter.next().is_none());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn procfs_socket_filtered() {
+    let listening = reader().tcp_filtered(&[SocketState::Listen]).unwrap();
+    assert_eq!(listening.len(), 2);
+    assert!(listening.iter().all(|s| s.state == SocketState::Listen));
+
+    let sockets = reader()
+        .tcp_filtered(&[SocketState::Listen, SocketState::Established])
+        .unwrap();
+    assert_eq!(sockets.len(), 3);
+
+    assert!(reader().tcp_filtered(&[]).unwrap().is_empty());
+    assert_eq!(
+        reader().udp_filtered(&[SocketState::Close]).unwrap().len(),
+        2
+    );
+    assert!(reader()
+        .udp_filtered(&[SocketState::Established])
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        reader()
+            .tcp6_filtered(&[SocketState::Listen])
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn procfs_listening_ports() {
+    // 631 listens on both 127.0.0.1 and ::1; 4402 is established.
+    assert_eq!(reader().listening_ports().unwrap(), vec![22, 631]);
+    assert!(ProcfsReader::from_root("/nonexistent")
+        .listening_ports()
+        .is_err());
+}
+
+#[test]
+fn procfs_missing_file() {
+    let reader = ProcfsReader::from_root("/nonexistent");
+    assert!(reader.stat().is_err());
+}
+
+#[test]
+fn procfs_loadavg() {
+    let loadavg = reader().loadavg().unwrap();
+    assert_eq!(loadavg.total, 834);
+    assert_eq!(loadavg.last_pid, 12345);
+}
+
+#[test]
+fn procfs_newest_process() {
+    let loadavg = reader().loadavg().unwrap();
+    let process = reader().newest_process(&loadavg).unwrap().unwrap();
+    assert_eq!(process.pid, 12345);
+    assert_eq!(process.comm, "sleep");
+    assert_eq!(process.ppid, 4242);
+
+    // The newest process has already exited.
+    let mut exited = loadavg.clone();
+    exited.last_pid = 99999;
+    assert_eq!(reader().newest_process(&exited).unwrap(), None);
+}
+
+#[test]
+fn procfs_uptime() {
+    let uptime = reader().uptime().unwrap();
+    assert_eq!(uptime.uptime, 12345.67);
+    assert_eq!(uptime.idle, 98765.43);
+}
+
+#[test]
+fn procfs_diskstats() {
+    let disks = reader().diskstats().unwrap();
+    assert_eq!(disks.len(), 5);
+    assert_eq!(disks[0].name, "loop0");
+    assert_eq!(disks[3].sectors_written, 61296144);
+}
+
+#[test]
+fn procfs_socket_pids() {
+    assert_eq!(reader().socket_pids(2796814).unwrap(), vec![4242, 4243]);
+    assert_eq!(reader().socket_pids(21354).unwrap(), vec![4242]);
+    assert_eq!(reader().socket_pids(30411).unwrap(), Vec::<u32>::new());
+}
+
+#[test]
+fn procfs_build_inode_pid_map() {
+    let map = reader().build_inode_pid_map().unwrap();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&2796814], vec![4242, 4243]);
+    assert_eq!(map[&21354], vec![4242]);
+}
+
+#[test]
+#[cfg(not(target_os = "linux"))]
+fn procfs_unsupported_platform() {
+    use std::io::ErrorKind;
+
+    assert_eq!(
+        linux_stats::stat().unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(
+        linux_stats::tcp().unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+}
+
+#[test]
+fn procfs_snapshot() {
+    let before = Instant::now();
+    let snapshot = reader().snapshot().unwrap();
+
+    assert!(snapshot.captured_at >= before);
+    assert_eq!(snapshot.stat, reader().stat().unwrap());
+    assert_eq!(snapshot.meminfo, reader().meminfo().unwrap());
+    assert_eq!(snapshot.loadavg, reader().loadavg().unwrap());
+    assert_eq!(snapshot.uptime, reader().uptime().unwrap());
+
+    assert!(ProcfsReader::from_root("/nonexistent").snapshot().is_err());
+}
+
+#[test]
+fn procfs_socket_snapshot() {
+    let before = Instant::now();
+    let previous = reader().tcp_snapshot().unwrap();
+    let current = reader().tcp_snapshot().unwrap();
+
+    assert!(previous.read_at >= before);
+    assert!(current.read_at <= Instant::now());
+    assert_eq!(previous.sockets, reader().tcp().unwrap());
+    assert_eq!(
+        current.interval_since(&previous),
+        current.read_at - previous.read_at
+    );
+    assert_eq!(previous.interval_since(&current), Duration::ZERO);
+
+    assert_eq!(
+        reader().udp_snapshot().unwrap().sockets,
+        reader().udp().unwrap()
+    );
+    assert!(ProcfsReader::from_root("/nonexistent")
+        .tcp_snapshot()
+        .is_err());
+}
+
+#[test]
+fn procfs_sysctls() {
+    assert_eq!(reader().hostname().unwrap(), "buildhost");
+    assert_eq!(reader().kernel_version().unwrap(), "6.1.0-18-amd64");
+    assert_eq!(reader().pid_max().unwrap(), 4194304);
+}
+
+#[test]
+fn procfs_socket_dedup() {
+    use std::collections::HashSet;
+
+    let mut seen = HashSet::new();
+    let sockets = reader().tcp().unwrap();
+    assert!(seen.insert(sockets[2].clone()));
+    assert!(!seen.insert(reader().tcp().unwrap()[2].clone()));
+    assert_eq!(seen.len(), 1);
+
+    assert!(seen.insert(sockets[0].clone()));
+    assert_eq!(seen.len(), 2);
+}
+
+#[test]
+fn procfs_cpu_usage_over() {
+    // The fixture never changes, so no time elapses between reads.
+    let interval = Duration::from_millis(1);
+    assert_eq!(reader().cpu_usage_over(interval).unwrap(), 0.0);
+    assert!(reader()
+        .cpus_usage_over(interval)
+        .unwrap()
+        .iter()
+        .all(|usage| *usage == 0.0));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn procfs_cpu_usage_over_live() {
+    let interval = Duration::from_millis(50);
+
+    let usage = linux_stats::cpu_usage_over(interval).unwrap();
+    assert!((0.0..=1.0).contains(&usage));
+
+    let usages = linux_stats::cpus_usage_over(interval).unwrap();
+    assert!(!usages.is_empty());
+    assert!(usages.iter().all(|usage| (0.0..=1.0).contains(usage)));
+}
+
+#[test]
+fn procfs_system_load() {
+    let load = reader().system_load(Duration::from_millis(1)).unwrap();
+    let meminfo = reader().meminfo().unwrap();
+
+    assert_eq!(load.cpu_percent, 0.0);
+    assert_eq!(load.mem_percent, meminfo.used_percent());
+    assert_eq!(load.swap_percent, meminfo.swap_used_percent());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn procfs_system_load_live() {
+    let load = linux_stats::system_load(Duration::from_millis(50)).unwrap();
+
+    assert!((0.0..=100.0).contains(&load.cpu_percent));
+    assert!((0.0..=100.0).contains(&load.mem_percent));
+    assert!((0.0..=100.0).contains(&load.swap_percent));
+}
+
+#[test]
+fn socket_state_try_from() {
+    assert_eq!(SocketState::try_from(0x0A).unwrap(), SocketState::Listen);
+    assert_eq!(
+        SocketState::try_from(0x01).unwrap(),
+        SocketState::Established
+    );
+
+    let err = SocketState::try_from(0xFF).unwrap_err();
+    assert_eq!(err.field(), "st");
+    assert_eq!(err.line(), "FF");
+    assert!(SocketState::try_from(0).is_err());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn socket_username() {
+    let mut socket = reader().tcp().unwrap().remove(0);
+
+    socket.uid = 0;
+    assert_eq!(socket.username().unwrap(), Some("root".to_owned()));
+
+    // Reserved by the kernel as an invalid uid.
+    socket.uid = u32::MAX;
+    assert_eq!(socket.username().unwrap(), None);
+}
+
+#[test]
+fn socket_is_wildcard() {
+    let sockets = reader().tcp().unwrap();
+    assert!(sockets[0].is_wildcard());
+    assert!(!sockets[2].is_wildcard());
+}
+
+#[test]
+fn procfs_watch_cpu() {
+    let (handle, rx) = reader().watch_cpu(Duration::from_millis(1));
+    let samples = rx.iter().take(2).collect::<Vec<f64>>();
+    assert_eq!(samples, vec![0.0, 0.0]);
+
+    // Dropping the receiver stops the thread.
+    drop(rx);
+    assert!(handle.join().unwrap().is_ok());
+
+    let (handle, rx) = ProcfsReader::from_root("/nonexistent").watch_cpu(Duration::from_millis(1));
+    assert!(rx.recv().is_err());
+    assert!(handle.join().unwrap().is_err());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn procfs_watch_cpu_live() {
+    let (_, rx) = linux_stats::watch_cpu(Duration::from_millis(20));
+    for usage in rx.iter().take(2) {
+        assert!((0.0..=1.0).contains(&usage));
+    }
+}
+
+#[test]
+fn socket_state_classifiers() {
+    use linux_stats::SocketState::*;
+
+    // (state, listening, connected, connecting, closing)
+    let cases = [
+        (Established, false, true, false, false),
+        (SynSent, false, false, true, false),
+        (SynRecv, false, false, true, false),
+        (FinWait1, false, false, false, true),
+        (FinWait2, false, false, false, true),
+        (TimeWait, false, false, false, true),
+        (Close, false, false, false, false),
+        (CloseWait, false, false, false, true),
+        (LastAck, false, false, false, true),
+        (Listen, true, false, false, false),
+        (Closing, false, false, false, true),
+    ];
+
+    for (state, listening, connected, connecting, closing) in cases {
+        assert_eq!(state.is_listening(), listening, "{}", state);
+        assert_eq!(state.is_connected(), connected, "{}", state);
+        assert_eq!(state.is_connecting(), connecting, "{}", state);
+        assert_eq!(state.is_closing(), closing, "{}", state);
+    }
+}
+
+#[test]
+fn socket_timer_timeout() {
+    let when = Duration::from_millis(200);
+
+    assert_eq!(SocketTimerState::Inactive.timeout(), None);
+    assert!(!SocketTimerState::Inactive.is_active());
+
+    for timer in [
+        SocketTimerState::Retransmit(when),
+        SocketTimerState::Keepalive(when),
+        SocketTimerState::TimeWait(when),
+        SocketTimerState::Probe(when),
+    ] {
+        assert_eq!(timer.timeout(), Some(when));
+        assert!(timer.is_active());
+    }
+}
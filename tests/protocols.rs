@@ -0,0 +1,42 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, Protocol};
+
+#[test]
+fn protocols() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let protocols = reader.protocols().unwrap();
+
+    assert_eq!(protocols.len(), 5);
+    assert_eq!(
+        protocols[3],
+        Protocol {
+            name: "TCP".to_owned(),
+            size: 2352,
+            sockets: 23,
+            memory: 6,
+            press: Some(false),
+            maxhdr: 320,
+            slab: true,
+            module: "kernel".to_owned(),
+        }
+    );
+
+    let udp = &protocols[2];
+    assert_eq!(udp.name, "UDP");
+    assert_eq!(udp.memory, 12);
+    assert_eq!(udp.press, None);
+
+    let packet = &protocols[0];
+    assert_eq!(packet.memory, -1);
+    assert!(!packet.slab);
+    assert_eq!(protocols[4].module, "sctp");
+}
+
+#[test]
+fn protocol_malformed() {
+    let err = "TCP 2352 23 6 maybe 320 yes kernel"
+        .parse::<Protocol>()
+        .unwrap_err();
+    assert_eq!(err.field(), "press");
+}
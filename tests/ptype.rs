@@ -0,0 +1,39 @@
+extern crate linux_stats;
+
+use linux_stats::{PacketType, ProcfsReader};
+
+#[test]
+fn ptype() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let handlers = reader.ptype().unwrap();
+
+    assert_eq!(handlers.len(), 4);
+    assert_eq!(
+        handlers[0],
+        PacketType {
+            device: Some("eth0".to_owned()),
+            type_: "ALL".to_owned(),
+            function: "packet_rcv+0x0/0x60".to_owned(),
+        }
+    );
+    assert_eq!(
+        handlers[1],
+        PacketType {
+            device: None,
+            type_: "0800".to_owned(),
+            function: "ip_rcv+0x0/0x3c0".to_owned(),
+        }
+    );
+    assert_eq!(handlers[3].type_, "86dd");
+}
+
+#[test]
+fn ptype_malformed() {
+    let err = "0800".parse::<PacketType>().unwrap_err();
+    assert_eq!(err.field(), "Function");
+
+    let err = "0800 eth0 ip_rcv+0x0/0x3c0 extra"
+        .parse::<PacketType>()
+        .unwrap_err();
+    assert_eq!(err.field(), "Device");
+}
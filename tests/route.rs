@@ -0,0 +1,73 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, Route, RouteFlags};
+
+use std::net::Ipv4Addr;
+
+#[test]
+fn route_default() {
+    let route = "eth0\t00000000\t0102A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0"
+        .parse::<Route>()
+        .unwrap();
+
+    assert_eq!(
+        route,
+        Route {
+            iface: "eth0".to_owned(),
+            destination: Ipv4Addr::UNSPECIFIED,
+            gateway: Ipv4Addr::new(192, 168, 2, 1),
+            flags: 0x3,
+            metric: 100,
+            mask: Ipv4Addr::UNSPECIFIED,
+            mtu: 0,
+            window: 0,
+        }
+    );
+}
+
+#[test]
+fn route_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let routes = reader.route().unwrap();
+
+    assert_eq!(routes.len(), 3);
+    assert_eq!(routes[1].destination, Ipv4Addr::new(192, 168, 2, 0));
+    assert_eq!(routes[1].mask, Ipv4Addr::new(255, 255, 255, 0));
+    assert_eq!(routes[1].mtu, 1500);
+    assert_eq!(routes[2].iface, "docker0");
+    assert_eq!(routes[2].destination, Ipv4Addr::new(172, 17, 0, 0));
+    assert_eq!(routes[2].mask, Ipv4Addr::new(255, 255, 0, 0));
+}
+
+#[test]
+fn route_malformed() {
+    let err = "eth0\t0000\t0102A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0"
+        .parse::<Route>()
+        .unwrap_err();
+    assert_eq!(err.field(), "Destination");
+
+    let err = "eth0\t00000000\t0102A8C0".parse::<Route>().unwrap_err();
+    assert_eq!(err.field(), "Flags");
+}
+
+#[test]
+fn route_flags() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let routes = reader.route().unwrap();
+
+    let flags = routes[0].flags_decoded();
+    assert_eq!(routes[0].flags, 0x3);
+    assert!(flags.is_up());
+    assert!(flags.is_gateway());
+    assert!(!flags.is_host());
+
+    let flags = routes[1].flags_decoded();
+    assert!(flags.is_up());
+    assert!(!flags.is_gateway());
+
+    let flags = RouteFlags(0x0205);
+    assert!(flags.is_host());
+    assert!(flags.contains(RouteFlags::REJECT));
+    assert!(!flags.contains(RouteFlags::GATEWAY));
+    assert_eq!(flags.bits(), 0x0205);
+}
@@ -0,0 +1,38 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, SchedStat, SchedStatCpu};
+
+#[test]
+fn schedstat() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let schedstat = reader.schedstat().unwrap();
+
+    assert_eq!(schedstat.version, 15);
+    assert_eq!(schedstat.timestamp, 4297299139);
+    assert_eq!(
+        schedstat.cpus,
+        vec![
+            SchedStatCpu {
+                cpu: 0,
+                run_time: 2745638361,
+                wait_time: 412556396,
+                timeslices: 13224,
+            },
+            SchedStatCpu {
+                cpu: 1,
+                run_time: 1923371830,
+                wait_time: 503114207,
+                timeslices: 11742,
+            },
+        ]
+    );
+}
+
+#[test]
+fn schedstat_malformed() {
+    let err = "version 15\ncpu0 1 2\n".parse::<SchedStat>().unwrap_err();
+    assert_eq!(err.field(), "pcount");
+
+    let err = "version x\n".parse::<SchedStat>().unwrap_err();
+    assert_eq!(err.field(), "version");
+}
@@ -0,0 +1,52 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, Snmp};
+
+#[test]
+fn snmp_pairing() {
+    let snmp = "Ip: Forwarding DefaultTTL InReceives
+Ip: 1 64 8213567
+Tcp: RtoAlgorithm MaxConn RetransSegs
+Tcp: 1 -1 21743
+"
+    .parse::<Snmp>()
+    .unwrap();
+
+    assert_eq!(snmp.protocols.len(), 2);
+    assert_eq!(snmp.get("Ip", "DefaultTTL"), Some(64));
+    assert_eq!(snmp.ip_in_receives(), Some(8213567));
+    assert_eq!(snmp.get("Tcp", "MaxConn"), Some(-1));
+    assert_eq!(snmp.tcp_retrans_segs(), Some(21743));
+    assert_eq!(snmp.udp_in_errors(), None);
+}
+
+#[test]
+fn snmp_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let snmp = reader.snmp().unwrap();
+
+    assert_eq!(snmp.protocols.len(), 6);
+    assert_eq!(snmp.ip_out_requests(), Some(6315842));
+    assert_eq!(snmp.get("Icmp", "OutEchoReps"), Some(20));
+    assert_eq!(snmp.get("IcmpMsg", "OutType3"), Some(1288));
+    assert_eq!(snmp.tcp_active_opens(), Some(53288));
+    assert_eq!(snmp.tcp_curr_estab(), Some(23));
+    assert_eq!(snmp.tcp_retrans_segs(), Some(21743));
+    assert_eq!(snmp.udp_in_datagrams(), Some(286233));
+    assert_eq!(snmp.udp_in_errors(), Some(7));
+    assert_eq!(snmp.get("UdpLite", "InErrors"), Some(0));
+}
+
+#[test]
+fn snmp_malformed() {
+    // Header without values.
+    assert!("Ip: Forwarding DefaultTTL\n".parse::<Snmp>().is_err());
+    // Values row for a different protocol.
+    assert!("Ip: Forwarding DefaultTTL\nTcp: 1 64\n"
+        .parse::<Snmp>()
+        .is_err());
+    // Fewer values than names.
+    assert!("Ip: Forwarding DefaultTTL\nIp: 1\n"
+        .parse::<Snmp>()
+        .is_err());
+}
@@ -0,0 +1,50 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, Snmp6};
+
+#[test]
+fn snmp6_lines() {
+    let snmp6 = "Ip6InReceives                   \t441329
+Ip6OutRequests                  \t398871
+Icmp6InMsgs                     \t3154
+"
+    .parse::<Snmp6>()
+    .unwrap();
+
+    assert_eq!(snmp6.values.len(), 3);
+    assert_eq!(snmp6.ip6_in_receives(), Some(441329));
+    assert_eq!(snmp6.ip6_out_requests(), Some(398871));
+    assert_eq!(snmp6.icmp6_in_msgs(), Some(3154));
+    assert_eq!(snmp6.udp6_in_datagrams(), None);
+
+    assert!("Ip6InReceives\n".parse::<Snmp6>().is_err());
+}
+
+#[test]
+fn snmp6_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let snmp6 = reader.snmp6().unwrap();
+
+    assert_eq!(snmp6.values.len(), 14);
+    assert_eq!(snmp6.icmp6_out_msgs(), Some(3260));
+    assert_eq!(snmp6.udp6_in_datagrams(), Some(10412));
+    assert_eq!(snmp6.get("Udp6InErrors"), Some(3));
+    assert_eq!(snmp6.get("Icmp6InType135"), Some(1201));
+}
+
+#[test]
+fn dev_snmp6() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+
+    let eth0 = reader.dev_snmp6("eth0").unwrap();
+    assert_eq!(eth0.len(), 5);
+    assert_eq!(eth0.get("ifIndex"), Some(&2));
+    assert_eq!(eth0.get("Ip6InReceives"), Some(&120488));
+
+    let all = reader.dev_snmp6_all().unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all["lo"].get("Udp6InDatagrams"), Some(&12));
+    assert_eq!(all["eth0"], eth0);
+
+    assert!(reader.dev_snmp6("wlan0").is_err());
+}
@@ -0,0 +1,41 @@
+extern crate linux_stats;
+
+use linux_stats::{Socket, SocketState, SocketTimerState};
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+#[test]
+fn socket_from_str() {
+    let sock = "  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1"
+        .parse::<Socket>()
+        .unwrap();
+    assert_eq!(sock.local_address, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+    assert_eq!(sock.local_port, 4402);
+    assert_eq!(
+        sock.remote_address,
+        IpAddr::V4(Ipv4Addr::new(46, 238, 65, 91))
+    );
+    assert_eq!(sock.remote_port, 80);
+    assert_eq!(sock.state, SocketState::Listen);
+    assert_eq!(sock.tx_queue, 0xA);
+    assert_eq!(sock.rx_queue, 2);
+    assert_eq!(
+        sock.timer,
+        SocketTimerState::Retransmit(Duration::from_millis(110))
+    );
+    assert_eq!(sock.uid, 1001);
+    assert_eq!(sock.inode, 2796814);
+
+    assert_eq!(linux_stats::parse_socket("  49: 0100007F:1132 5B41EE2E:0050 0A 0000000A:00000002 01:0000000B 00000000  1001        0 2796814 1 ffff938ed0741080 20 4 29 10 -1").unwrap(), sock);
+}
+
+#[test]
+fn socket_from_str_malformed() {
+    let err = "  49: 0100007F:1132 5B41EE2E:0050"
+        .parse::<Socket>()
+        .unwrap_err();
+    assert_eq!(err.field(), "st");
+
+    assert!(linux_stats::parse_socket("").is_err());
+}
@@ -0,0 +1,28 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, SockStat};
+
+#[test]
+fn sockstat() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+
+    assert_eq!(
+        reader.sockstat().unwrap(),
+        SockStat {
+            sockets_used: 1207,
+            tcp_inuse: 23,
+            tcp_orphan: 1,
+            tcp_tw: 4,
+            tcp_alloc: 31,
+            tcp_mem: 6,
+            udp_inuse: 9,
+            udp_mem: 12,
+        }
+    );
+}
+
+#[test]
+fn sockstat_malformed() {
+    let err = "TCP: inuse 23 orphan\n".parse::<SockStat>().unwrap_err();
+    assert_eq!(err.line(), "TCP: inuse 23 orphan");
+}
@@ -0,0 +1,56 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, SoftnetStat};
+
+#[test]
+fn softnet_stat() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let cpus = reader.softnet_stat().unwrap();
+
+    assert_eq!(
+        cpus,
+        vec![
+            SoftnetStat {
+                processed: 0x4a1b2,
+                dropped: 0,
+                time_squeeze: 3,
+                cpu_collision: 0,
+                received_rps: 0x10,
+                flow_limit_count: 0,
+            },
+            SoftnetStat {
+                processed: 0xff00,
+                dropped: 0xa,
+                time_squeeze: 0x1f,
+                cpu_collision: 2,
+                received_rps: 0,
+                flow_limit_count: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn softnet_stat_short() {
+    // Older kernels print only nine columns.
+    let cpu = "00000100 00000001 00000002 00000000 00000000 00000000 00000000 00000000 00000003"
+        .parse::<SoftnetStat>()
+        .unwrap();
+    assert_eq!(cpu.processed, 0x100);
+    assert_eq!(cpu.cpu_collision, 3);
+    assert_eq!(cpu.received_rps, 0);
+    assert_eq!(cpu.flow_limit_count, 0);
+}
+
+#[test]
+fn softnet_stat_malformed() {
+    let err = "0000010g 00000001 00000002"
+        .parse::<SoftnetStat>()
+        .unwrap_err();
+    assert_eq!(err.field(), "processed");
+
+    let err = "00000100 00000001 00000002"
+        .parse::<SoftnetStat>()
+        .unwrap_err();
+    assert_eq!(err.field(), "cpu_collision");
+}
@@ -1,6 +1,8 @@
 extern crate linux_stats;
 
-use linux_stats::Stat;
+use linux_stats::{CpuTime, ParseOptions, SoftIrq, Stat};
+
+use std::time::{Duration, UNIX_EPOCH};
 
 #[test]
 fn stat_empty() {
@@ -22,6 +24,8 @@ fn stat_1() {
         procs_running: 1,
         procs_blocked: 0,
         softirq: vec![183433, 0, 21755, 12, 39, 1137, 231, 21459, 2263],
+        page: None,
+        swap: None,
     };
 
     assert_eq!(include_str!("./stat-1").parse::<Stat>().unwrap(), stat_1);
@@ -83,7 +87,383 @@ fn stat_2() {
         softirq: vec![
             22365982, 686253, 10585458, 7389, 593509, 232918, 3, 176106, 5806866, 10421, 4267059,
         ],
+        page: None,
+        swap: None,
     };
 
     assert_eq!(include_str!("./stat-2").parse::<Stat>().unwrap(), stat_2);
 }
+
+#[test]
+fn stat_cpu_times() {
+    let stat = include_str!("./stat-2").parse::<Stat>().unwrap();
+
+    assert_eq!(
+        stat.cpu_time(),
+        CpuTime {
+            user: 273155,
+            nice: 3,
+            system: 319698,
+            idle: 6079941,
+            iowait: 3481,
+            irq: 35941,
+            softirq: 14468,
+            steal: 0,
+            guest: 0,
+            guest_nice: 0,
+        }
+    );
+
+    let cpus = stat.cpu_times();
+    assert_eq!(cpus.len(), 4);
+    assert_eq!(cpus[1].user, 67926);
+    assert_eq!(cpus[1].idle, 1499677);
+    assert_eq!(cpus[3].nice, 1);
+    assert_eq!(cpus[3].softirq, 2335);
+}
+
+#[test]
+fn cpu_time_old_kernel() {
+    let cpu = "cpu0 1132 34 1441 11311718 3675 127 438"
+        .parse::<CpuTime>()
+        .unwrap();
+
+    assert_eq!(cpu.softirq, 438);
+    assert_eq!(cpu.steal, 0);
+    assert_eq!(cpu.guest, 0);
+    assert_eq!(cpu.guest_nice, 0);
+}
+
+#[test]
+fn cpu_time_malformed() {
+    assert!("intr 1 2 3".parse::<CpuTime>().is_err());
+    assert!("cpu0 1132 x 1441".parse::<CpuTime>().is_err());
+}
+
+#[test]
+fn stat_cpu_usage_since() {
+    let previous = include_str!("./stat-1").parse::<Stat>().unwrap();
+    let mut current = previous.clone();
+    // The aggregate line sees 600 jiffies elapse, 200 of them busy.
+    current.cpu[0] += 200;
+    current.cpu[3] += 300;
+    current.cpu[4] += 100;
+    for cpu in &mut current.cpus {
+        cpu[0] += 100;
+        cpu[3] += 150;
+    }
+    current.cpus[1][4] += 50;
+
+    assert!((current.cpu_usage_since(&previous) - 200.0 / 600.0).abs() < 1e-9);
+    assert_eq!(
+        current.cpus_usage_since(&previous),
+        vec![0.4, 100.0 / 300.0]
+    );
+}
+
+#[test]
+fn stat_cpu_usage_since_edge_cases() {
+    let stat = include_str!("./stat-2").parse::<Stat>().unwrap();
+    assert_eq!(stat.cpu_usage_since(&stat), 0.0);
+
+    let mut previous = stat.clone();
+    previous.cpus.truncate(2);
+    assert_eq!(stat.cpus_usage_since(&previous), vec![0.0; 4]);
+}
+
+#[test]
+fn stat_per_cpu() {
+    let stat = "cpu  30 0 30 300
+cpu0 10 0 20 100
+cpu1 20 0 10 200
+"
+    .parse::<Stat>()
+    .unwrap();
+
+    let cores = stat.per_cpu().collect::<Vec<_>>();
+    assert_eq!(
+        cores,
+        vec![(0, &vec![10, 0, 20, 100]), (1, &vec![20, 0, 10, 200])]
+    );
+
+    let times = stat.per_cpu_time().collect::<Vec<_>>();
+    assert_eq!(times.len(), 2);
+    assert_eq!(times[1].0, 1);
+    assert_eq!(times[1].1.user, 20);
+    assert_eq!(times[1].1.idle, 200);
+
+    assert_eq!(Stat::default().per_cpu().count(), 0);
+}
+
+#[test]
+fn stat_softirq_breakdown() {
+    let stat = include_str!("./stat-2").parse::<Stat>().unwrap();
+
+    assert_eq!(
+        stat.softirq_breakdown(),
+        SoftIrq {
+            hi: 686253,
+            timer: 10585458,
+            net_tx: 7389,
+            net_rx: 593509,
+            block: 232918,
+            irq_poll: 3,
+            tasklet: 176106,
+            sched: 5806866,
+            hrtimer: 10421,
+            rcu: 4267059,
+        }
+    );
+}
+
+#[test]
+fn stat_softirq_breakdown_old_kernel() {
+    // Predates both IRQ_POLL and RCU.
+    let stat = include_str!("./stat-1").parse::<Stat>().unwrap();
+
+    assert_eq!(
+        stat.softirq_breakdown(),
+        SoftIrq {
+            hi: 0,
+            timer: 21755,
+            net_tx: 12,
+            net_rx: 39,
+            block: 1137,
+            irq_poll: 0,
+            tasklet: 231,
+            sched: 21459,
+            hrtimer: 2263,
+            rcu: 0,
+        }
+    );
+
+    assert_eq!(Stat::default().softirq_breakdown(), SoftIrq::default());
+}
+
+#[test]
+fn stat_softirq_delta() {
+    let previous = Stat {
+        softirq: vec![100, 1, 10, 0, 20, 5, 0, 4, 30, 0, 30],
+        ..Default::default()
+    };
+    let current = Stat {
+        softirq: vec![160, 1, 25, 0, 40, 5, 0, 9, 35, 0, 45],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        current.softirq_delta(&previous),
+        SoftIrq {
+            hi: 0,
+            timer: 15,
+            net_tx: 0,
+            net_rx: 20,
+            block: 0,
+            irq_poll: 0,
+            tasklet: 5,
+            sched: 5,
+            hrtimer: 0,
+            rcu: 15,
+        }
+    );
+    assert_eq!(current.total_softirq_delta(&previous), 60);
+
+    // Counters going backwards saturate rather than underflow.
+    assert_eq!(previous.softirq_delta(&current), SoftIrq::default());
+    assert_eq!(previous.total_softirq_delta(&current), 0);
+}
+
+#[test]
+fn stat_interrupts() {
+    let stat = include_str!("./stat-1").parse::<Stat>().unwrap();
+
+    assert_eq!(stat.total_interrupts(), 114930548);
+    assert_eq!(
+        stat.interrupt_counts(),
+        &[113199788, 3, 0, 5, 263, 0, 4][..]
+    );
+
+    assert_eq!(Stat::default().total_interrupts(), 0);
+    assert!(Stat::default().interrupt_counts().is_empty());
+}
+
+#[test]
+fn stat_malformed() {
+    let strict = ParseOptions::strict();
+
+    let err = Stat::from_str_with("cpu  10 20 x 40\n", strict).unwrap_err();
+    assert_eq!(err.field(), "value");
+    assert_eq!(err.line(), "cpu  10 20 x 40");
+
+    let err = Stat::from_str_with("cpu  10 20 30 40\nctxt\n", strict).unwrap_err();
+    assert_eq!(err.field(), "ctxt");
+}
+
+#[test]
+fn stat_parse_options() {
+    let content = "cpu  10 20 x 40\nctxt 1a\nprocesses 7\n";
+
+    let err = Stat::from_str_with(content, ParseOptions::strict()).unwrap_err();
+    assert_eq!(err.field(), "value");
+
+    let stat = Stat::from_str_with(content, ParseOptions::lenient()).unwrap();
+    assert_eq!(stat.cpu, vec![10, 20, 0, 40]);
+    assert_eq!(stat.cpu_time().idle, 40);
+    assert_eq!(stat.ctxt, 0);
+    assert_eq!(stat.processes, 7);
+
+    // `FromStr` is lenient.
+    assert_eq!(ParseOptions::default(), ParseOptions::lenient());
+    assert_eq!(content.parse::<Stat>().unwrap(), stat);
+}
+
+#[test]
+fn stat_page_swap() {
+    let stat = "cpu  10 20 30 40
+page 12 34
+swap 0 7
+ctxt 5
+"
+    .parse::<Stat>()
+    .unwrap();
+
+    assert_eq!(stat.page, Some((12, 34)));
+    assert_eq!(stat.swap, Some((0, 7)));
+    assert_eq!(stat.ctxt, 5);
+
+    let content = "cpu  10 20 30 40\npage 12\n";
+    assert!(Stat::from_str_with(content, ParseOptions::strict()).is_err());
+    assert_eq!(content.parse::<Stat>().unwrap().page, None);
+}
+
+#[test]
+fn stat_boot_time() {
+    let stat = include_str!("./stat-2").parse::<Stat>().unwrap();
+    assert_eq!(
+        stat.boot_time(),
+        UNIX_EPOCH + Duration::from_secs(1568127349)
+    );
+
+    // Past the 2106 rollover of a 32-bit btime.
+    let stat = "cpu  1 2 3 4\nbtime 4294967296\n".parse::<Stat>().unwrap();
+    assert_eq!(stat.btime, 4294967296);
+
+    assert_eq!(Stat::default().boot_time(), UNIX_EPOCH);
+}
+
+#[test]
+fn stat_old_kernel_intr() {
+    // No softirq line, and an intr line with a stray token: degrades to 0 in
+    // that column rather than failing the whole file.
+    let stat = "cpu  1 2 3 4
+intr 1462898 1462 0 0 ? 4
+ctxt 9
+"
+    .parse::<Stat>()
+    .unwrap();
+    assert_eq!(stat.intr, vec![1462898, 1462, 0, 0, 0, 4]);
+    assert!(stat.softirq.is_empty());
+    assert_eq!(stat.cpu, vec![1, 2, 3, 4]);
+    assert_eq!(stat.ctxt, 9);
+
+    let stat = "cpu  1 2 3 4\nintr 1462898 1462 0\nctxt 9\n"
+        .parse::<Stat>()
+        .unwrap();
+    assert!(stat.softirq.is_empty());
+    assert_eq!(stat.intr, vec![1462898, 1462, 0]);
+}
+
+#[test]
+fn stat_forks_and_ctxt_since() {
+    let previous = Stat {
+        processes: 56020,
+        ctxt: 1000,
+        ..Default::default()
+    };
+    let current = Stat {
+        processes: 56045,
+        ctxt: 1750,
+        ..Default::default()
+    };
+
+    assert_eq!(current.forks_since(&previous), 25);
+    assert_eq!(current.ctxt_switches_since(&previous), 750);
+
+    // A reset counter saturates to zero.
+    assert_eq!(previous.forks_since(&current), 0);
+    assert_eq!(previous.ctxt_switches_since(&current), 0);
+}
+
+#[test]
+fn stat_num_cpus() {
+    let stat = include_str!("./stat-1").parse::<Stat>().unwrap();
+    assert_eq!(stat.num_cpus(), 2);
+    assert!(stat.has_per_cpu());
+
+    let stat = "cpu  10 20 30 40\nctxt 5\n".parse::<Stat>().unwrap();
+    assert_eq!(stat.num_cpus(), 0);
+    assert!(!stat.has_per_cpu());
+}
+
+#[test]
+fn stat_deltas_after_reset() {
+    assert_eq!(linux_stats::delta(10, 4), 6);
+    assert_eq!(linux_stats::delta(4, 10), 0);
+
+    let previous = include_str!("./stat-2").parse::<Stat>().unwrap();
+    let current = include_str!("./stat-1").parse::<Stat>().unwrap();
+    // Pretend `current` was sampled after a reboot, so every counter is lower.
+    assert!(current.ctxt < previous.ctxt);
+    assert_eq!(current.forks_since(&previous), 0);
+    assert_eq!(current.ctxt_switches_since(&previous), 0);
+    assert_eq!(current.total_softirq_delta(&previous), 0);
+    assert_eq!(current.softirq_delta(&previous), SoftIrq::default());
+    assert_eq!(current.cpu_usage_since(&previous), 0.0);
+}
+
+#[test]
+fn stat_aggregate_only() {
+    let opts = ParseOptions::strict().aggregate_only();
+    let stat = Stat::from_str_with(include_str!("./stat-2"), opts).unwrap();
+    let full = include_str!("./stat-2").parse::<Stat>().unwrap();
+
+    assert!(stat.cpus.is_empty());
+    assert_eq!(stat.cpu, full.cpu);
+    assert_eq!(stat.ctxt, full.ctxt);
+    assert_eq!(stat.intr, full.intr);
+}
+
+#[test]
+fn cpu_time_as_duration() {
+    if linux_stats::clk_tck() == 100 {
+        assert_eq!(CpuTime::as_duration(250), Duration::from_millis(2500));
+        assert_eq!(CpuTime::as_duration(1), Duration::from_millis(10));
+    }
+    assert_eq!(CpuTime::as_duration(0), Duration::ZERO);
+    assert_eq!(CpuTime::as_duration(u64::MAX), Duration::MAX);
+
+    let hz = linux_stats::clk_tck();
+    let cpu = "cpu  10 20 30 40 50".parse::<CpuTime>().unwrap();
+    assert_eq!(
+        CpuTime::as_duration(cpu.total()),
+        Duration::from_secs(150) / hz as u32
+    );
+    assert_eq!(
+        CpuTime::as_duration(cpu.idle_time()),
+        Duration::from_secs(90) / hz as u32
+    );
+}
+
+#[test]
+fn stat_cpu_field() {
+    let stat = "cpu  10 20 30 40\n".parse::<Stat>().unwrap();
+    assert_eq!(stat.cpu_field(1), Some(20));
+    assert_eq!(stat.user(), Some(10));
+    assert_eq!(stat.idle(), Some(40));
+    assert_eq!(stat.iowait(), None);
+    assert_eq!(stat.cpu_field(9), None);
+
+    let stat = include_str!("./stat-1").parse::<Stat>().unwrap();
+    assert_eq!(stat.iowait(), Some(stat.cpu[4]));
+    assert_eq!(Stat::default().user(), None);
+}
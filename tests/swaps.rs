@@ -0,0 +1,37 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, Swap};
+
+#[test]
+fn swaps() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let swaps = reader.swaps().unwrap();
+
+    assert_eq!(
+        swaps,
+        vec![
+            Swap {
+                filename: "/dev/nvme0n1p3".to_owned(),
+                kind: "partition".to_owned(),
+                size_kb: 8388604,
+                used_kb: 1024,
+                priority: -2,
+            },
+            Swap {
+                filename: "/var/swap file".to_owned(),
+                kind: "file".to_owned(),
+                size_kb: 2097148,
+                used_kb: 0,
+                priority: 10,
+            },
+        ]
+    );
+}
+
+#[test]
+fn swaps_malformed() {
+    assert!("/dev/sda2 partition 8388604".parse::<Swap>().is_err());
+    assert!("/dev/sda2 partition 8388604 0 high"
+        .parse::<Swap>()
+        .is_err());
+}
@@ -0,0 +1,64 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, UnixSocket};
+
+#[test]
+fn unix_named() {
+    let sock = "ffff8f8a4f1c8000: 00000002 00000000 00010000 0001 01 22042 /run/systemd/private"
+        .parse::<UnixSocket>()
+        .unwrap();
+
+    assert_eq!(
+        sock,
+        UnixSocket {
+            ref_count: 2,
+            protocol: 0,
+            flags: 0x10000,
+            socket_type: 1,
+            state: 1,
+            inode: 22042,
+            path: Some("/run/systemd/private".to_owned()),
+        }
+    );
+}
+
+#[test]
+fn unix_unnamed() {
+    let sock = "ffff8f8a4f1c8800: 00000003 00000000 00000000 0001 03 24115"
+        .parse::<UnixSocket>()
+        .unwrap();
+
+    assert_eq!(sock.ref_count, 3);
+    assert_eq!(sock.state, 3);
+    assert_eq!(sock.inode, 24115);
+    assert_eq!(sock.path, None);
+}
+
+#[test]
+fn unix_file() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let sockets = reader.unix().unwrap();
+
+    assert_eq!(sockets.len(), 4);
+    assert_eq!(
+        sockets[1].path.as_deref(),
+        Some("@/org/kernel/linux/storage/multipathd")
+    );
+    assert_eq!(sockets[2].path, None);
+    assert_eq!(sockets[3].socket_type, 2);
+    assert_eq!(sockets[3].inode, 913);
+    assert_eq!(
+        sockets[3].path.as_deref(),
+        Some("/tmp/dir with spaces/app.sock")
+    );
+}
+
+#[test]
+fn unix_malformed() {
+    assert!("ffff8f8a4f1c8000: 00000002 00000000"
+        .parse::<UnixSocket>()
+        .is_err());
+    assert!("ffff8f8a4f1c8000: 00000002 00000000 00010000 0001 01 abc"
+        .parse::<UnixSocket>()
+        .is_err());
+}
@@ -0,0 +1,24 @@
+extern crate linux_stats;
+
+use linux_stats::Uptime;
+
+#[test]
+fn uptime() {
+    let uptime = "12345.67 98765.43\n".parse::<Uptime>().unwrap();
+
+    assert_eq!(
+        uptime,
+        Uptime {
+            uptime: 12345.67,
+            idle: 98765.43,
+        }
+    );
+    assert_eq!(uptime.uptime_duration().as_millis(), 12345670);
+    assert_eq!(uptime.idle_duration().as_secs(), 98765);
+}
+
+#[test]
+fn uptime_malformed() {
+    assert!("".parse::<Uptime>().is_err());
+    assert!("12345.67".parse::<Uptime>().is_err());
+}
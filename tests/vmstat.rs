@@ -0,0 +1,31 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, VmStat};
+
+#[test]
+fn vmstat_empty() {
+    assert_eq!("".parse::<VmStat>().unwrap(), Default::default());
+}
+
+#[test]
+fn vmstat_1() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let vmstat = reader.vmstat().unwrap();
+
+    assert_eq!(vmstat.values.len(), 32);
+    assert_eq!(vmstat.get("numa_interleave"), Some(42360));
+    assert_eq!(vmstat.get("pgpgout"), Some(4873904));
+    assert_eq!(vmstat.get("nr_unknown"), None);
+
+    assert_eq!(vmstat.pgfault(), Some(78513376));
+    assert_eq!(vmstat.pgmajfault(), Some(9815));
+    assert_eq!(vmstat.pswpin(), Some(17));
+    assert_eq!(vmstat.pswpout(), Some(203));
+    assert_eq!(vmstat.nr_free_pages(), Some(3048904));
+}
+
+#[test]
+fn vmstat_malformed() {
+    assert!("pgfault\n".parse::<VmStat>().is_err());
+    assert!("pgfault abc\n".parse::<VmStat>().is_err());
+}
@@ -0,0 +1,38 @@
+extern crate linux_stats;
+
+use linux_stats::{ProcfsReader, Wireless};
+
+#[test]
+fn wireless() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let interfaces = reader.wireless().unwrap();
+
+    assert_eq!(interfaces.len(), 2);
+    assert_eq!(
+        interfaces[0],
+        Wireless {
+            interface: "wlan0".to_owned(),
+            status: 0,
+            link_quality: 70.0,
+            signal_level: -40.0,
+            noise_level: -256.0,
+            discarded_nwid: 0,
+            discarded_crypt: 3,
+            missed_beacon: 5,
+        }
+    );
+    assert_eq!(interfaces[1].interface, "wlp3s0");
+    assert_eq!(interfaces[1].link_quality, 52.0);
+    assert_eq!(interfaces[1].discarded_nwid, 1);
+}
+
+#[test]
+fn wireless_malformed() {
+    let err = " wlan0: 0000   good.  -40.  -256        0      3      0      0     12        5"
+        .parse::<Wireless>()
+        .unwrap_err();
+    assert_eq!(err.field(), "link");
+
+    let err = " wlan0: 0000   70.  -40.".parse::<Wireless>().unwrap_err();
+    assert_eq!(err.field(), "noise");
+}
@@ -0,0 +1,28 @@
+extern crate linux_stats;
+
+use linux_stats::ProcfsReader;
+
+#[test]
+fn zoneinfo() {
+    let reader = ProcfsReader::from_root(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/proc"));
+    let zones = reader.zoneinfo().unwrap();
+
+    assert_eq!(zones.len(), 2);
+
+    assert_eq!(zones[0].node, 0);
+    assert_eq!(zones[0].zone, "DMA");
+    assert_eq!(zones[0].free_pages, 3840);
+    assert_eq!(zones[0].min, 11);
+    assert_eq!(zones[0].low, 14);
+    assert_eq!(zones[0].high, 17);
+    assert_eq!(zones[0].counters.get("nr_free_pages"), Some(&3840));
+    // Per-node stats are not attributed to the zone.
+    assert_eq!(zones[0].counters.get("nr_inactive_anon"), None);
+    assert_eq!(zones[0].counters.len(), 4);
+
+    // The pageset `high:` must not override the watermark.
+    assert_eq!(zones[1].zone, "Normal");
+    assert_eq!(zones[1].high, 22929);
+    assert_eq!(zones[1].min, 15287);
+    assert_eq!(zones[1].counters.get("nr_mlock"), Some(&12));
+}